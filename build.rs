@@ -0,0 +1,48 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+// Every .rs file under a directory, in no particular order.
+fn sources(dir: &Path, found: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            sources(&path, found)?;
+        } else if path.extension().is_some_and(|e| e == "rs") {
+            found.push(path);
+        }
+    }
+    return Ok(());
+}
+
+// Whether a file is one day's own module, like src/days/day14.rs.
+fn is_day(path: &Path) -> bool {
+    let name = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+    path.parent().and_then(Path::file_name) == Some("days".as_ref())
+        && name
+            .strip_prefix("day")
+            .is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+}
+
+// Writes the list of sources the answer cache's version hash is made from:
+// every module but the days' own, which the cache hashes one at a time so
+// that changing one day keeps the answers of the others.
+fn main() -> io::Result<()> {
+    let src = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src");
+    println!("cargo:rerun-if-changed={}", src.display());
+
+    let mut found = Vec::new();
+    sources(&src, &mut found)?;
+    found.retain(|path| !is_day(path));
+    found.sort();
+
+    let mut out = String::from("&[\n");
+    for path in found {
+        out.push_str(&format!("    include_str!({:?}),\n", path));
+    }
+    out.push_str("]\n");
+
+    let dest = Path::new(&env::var("OUT_DIR").unwrap()).join("shared_sources.rs");
+    return fs::write(dest, out);
+}
@@ -0,0 +1,74 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::data;
+use crate::Puzzle;
+
+// Accepted answers are for the real inputs, so they live next to them rather
+// than in the repo.
+pub fn path() -> PathBuf {
+    data::dir().join("answers.txt")
+}
+
+// Multi-line answers (the CRT picture in day 10) are kept on one line with
+// their newlines escaped.
+pub(crate) fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+
+pub(crate) fn unescape(answer: &str) -> String {
+    let mut out = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n') => out.push('\n'),
+                Some(c) => out.push(c),
+                None => out.push('\\'),
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    return out;
+}
+
+// Reads the accepted answers file, which has one "<puzzle> <answer>" line per
+// puzzle. A missing file has no answers.
+pub fn read(path: &Path) -> io::Result<HashMap<Puzzle, String>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(HashMap::new()),
+        Err(e) => return Err(e),
+    };
+
+    let mut answers = HashMap::new();
+    for line in contents.lines().filter(|l| !l.is_empty()) {
+        let entry = line
+            .split_once(' ')
+            .and_then(|(puzzle, answer)| Some((puzzle.parse().ok()?, unescape(answer))));
+        let Some((puzzle, answer)) = entry else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("bad answers line '{}'", line),
+            ));
+        };
+        answers.insert(puzzle, answer);
+    }
+
+    return Ok(answers);
+}
+
+// Writes the accepted answers file, in puzzle order.
+pub fn write(path: &Path, answers: &HashMap<Puzzle, String>) -> io::Result<()> {
+    let mut answers: Vec<_> = answers.iter().collect();
+    answers.sort_by_key(|(puzzle, _)| (puzzle.day, puzzle.part as u8));
+
+    let contents: String = answers
+        .iter()
+        .map(|(puzzle, answer)| format!("{} {}\n", puzzle, escape(answer)))
+        .collect();
+    fs::write(path, contents)
+}
@@ -0,0 +1,226 @@
+use std::fs;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use crate::config::InputKind;
+use crate::data;
+use crate::days::{self, Day};
+use crate::{panic_message, Answer, Context, Part, Puzzle, Timing};
+
+// How one run of a solution ended.
+#[derive(Clone, Debug)]
+pub enum Outcome {
+    Solved(Answer, Timing),
+    // The input didn't parse or the solution panicked.
+    Failed(String),
+    TimedOut,
+}
+
+// Solves an input on a thread of its own, giving up on it once the time limit
+// is up. There's no stopping a thread from outside, so a solution that runs
+// out of time is left to carry on in the background until the process exits.
+pub fn solve_with_timeout(
+    day: &'static Day,
+    part: Part,
+    input: String,
+    ctx: Context,
+    timeout: Duration,
+) -> Outcome {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let solved = panic::catch_unwind(AssertUnwindSafe(|| day.solve_timed(part, &input, &ctx)));
+        // Nobody is listening any more if this took too long.
+        let _ = sender.send(solved);
+    });
+
+    return match receiver.recv_timeout(timeout) {
+        Ok(Ok(Ok((answer, timing)))) => Outcome::Solved(answer, timing),
+        Ok(Ok(Err(e))) => Outcome::Failed(e.to_string()),
+        Ok(Err(payload)) => Outcome::Failed(format!("panicked: {}", panic_message(&*payload))),
+        Err(RecvTimeoutError::Timeout) => Outcome::TimedOut,
+        Err(RecvTimeoutError::Disconnected) => {
+            unreachable!("solving thread should send its result or still be running")
+        }
+    };
+}
+
+// How checking and solving one puzzle went.
+#[derive(Clone, Debug)]
+pub struct PuzzleReport {
+    pub puzzle: Puzzle,
+    // Whether every sample gave its expected answer, or None if there are no
+    // samples.
+    pub samples: Option<bool>,
+    // Answer to the real input, if there is one and it was solved.
+    pub answer: Option<Answer>,
+    // Time spent solving the samples and the real input together.
+    pub elapsed: Duration,
+    // Whether every answer came from the cache, without solving anything.
+    pub cached: bool,
+    // What went wrong with each sample or real input that failed.
+    pub errors: Vec<String>,
+}
+
+impl PuzzleReport {
+    pub fn passed(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("could not read {}: {}", path.display(), e))
+}
+
+// Checks a puzzle against its samples and solves its real input, if it has
+// one, with each solution given up on after the time limit. Answers are taken
+// from and added to the cache if it's in use.
+fn run_puzzle(
+    puzzle: Puzzle,
+    params: &[(String, i64)],
+    timeout: Duration,
+    use_cache: bool,
+) -> PuzzleReport {
+    let day = days::get(puzzle.day).expect("puzzle should have a solution");
+    let mut errors = Vec::new();
+    let mut elapsed = Duration::ZERO;
+    let mut cached = true;
+
+    // Overrides are given for every puzzle at once, so each day only takes
+    // the ones for parameters it has.
+    let params: Vec<(String, i64)> = params
+        .iter()
+        .filter(|(name, _)| day.params.iter().any(|p| p.name == name))
+        .cloned()
+        .collect();
+
+    // The samples and the real input can have their own parameter values.
+    let setup = |kind| {
+        let params = day.configured_params(kind, &params);
+        day.context(&params).map(|ctx| (params, ctx))
+    };
+    let found = setup(InputKind::Sample).and_then(|sample_setup| {
+        let real_setup = setup(InputKind::Real)?;
+        let samples = data::samples(puzzle)
+            .map_err(|e| format!("could not read {}: {}", data::dir().display(), e))?;
+        Ok((sample_setup, real_setup, samples))
+    });
+    let (sample_setup, real_setup, samples) = match found {
+        Ok(found) => found,
+        Err(e) => {
+            return PuzzleReport {
+                puzzle: puzzle,
+                samples: None,
+                answer: None,
+                elapsed: elapsed,
+                cached: false,
+                errors: vec![e],
+            };
+        }
+    };
+
+    let mut solve = |label: &str, input: String, (params, ctx): &(Vec<(String, i64)>, Context)| {
+        let solved = day.solve_cached(puzzle.part, &input, params, use_cache, || {
+            cached = false;
+            match solve_with_timeout(day, puzzle.part, input.clone(), ctx.clone(), timeout) {
+                Outcome::Solved(answer, timing) => {
+                    elapsed += timing.total();
+                    Ok((answer, timing))
+                }
+                Outcome::Failed(e) => Err(format!("{}: {}", label, e)),
+                Outcome::TimedOut => {
+                    elapsed += timeout;
+                    Err(format!("{}: timed out after {:?}", label, timeout))
+                }
+            }
+        });
+        solved.map(|(answer, _)| answer)
+    };
+
+    let mut samples_passed = None;
+    for sample in &samples {
+        let checked = read(&sample.input).and_then(|input| {
+            let expected = read(&sample.output)?;
+            let answer = solve(&sample.name(), input, &sample_setup)?;
+            if answer.to_string() == expected.trim_end() {
+                Ok(())
+            } else {
+                Err(format!("{}: wrong answer", sample.name()))
+            }
+        });
+        if let Err(e) = checked {
+            errors.push(e);
+        }
+        samples_passed = Some(errors.is_empty());
+    }
+
+    let path = data::input_path(puzzle);
+    let answer = match fs::read_to_string(&path) {
+        Ok(input) => solve("input", input, &real_setup)
+            .map_err(|e| errors.push(e))
+            .ok(),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+        Err(e) => {
+            errors.push(format!("could not read {}: {}", path.display(), e));
+            None
+        }
+    };
+
+    return PuzzleReport {
+        puzzle: puzzle,
+        samples: samples_passed,
+        answer: answer,
+        elapsed: elapsed,
+        cached: cached,
+        errors: errors,
+    };
+}
+
+// Checks and solves the puzzles on a pool of worker threads, with each
+// solution given up on after the time limit, and answers taken from the cache
+// where possible if it's in use. Calls `done` as each puzzle finishes, and
+// returns the reports in the same order as the puzzles.
+pub fn run(
+    puzzles: &[Puzzle],
+    params: &[(String, i64)],
+    jobs: usize,
+    timeout: Duration,
+    use_cache: bool,
+    done: impl Fn(&PuzzleReport) + Sync,
+) -> Vec<PuzzleReport> {
+    let next = AtomicUsize::new(0);
+    let reports = Mutex::new(Vec::new());
+
+    // Panics are reported with the puzzle they happened in, so keep the
+    // default hook from printing them as well, jumbled up between threads.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, puzzles.len().max(1)) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(&puzzle) = puzzles.get(i) else {
+                    break;
+                };
+                let report = run_puzzle(puzzle, params, timeout, use_cache);
+                done(&report);
+                reports
+                    .lock()
+                    .expect("reports should not be poisoned")
+                    .push((i, report));
+            });
+        }
+    });
+    panic::set_hook(hook);
+
+    let mut reports = reports
+        .into_inner()
+        .expect("reports should not be poisoned");
+    reports.sort_by_key(|&(i, _)| i);
+    return reports.into_iter().map(|(_, report)| report).collect();
+}
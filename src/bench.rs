@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::data;
+use crate::Puzzle;
+
+// How much slower than its baseline a puzzle's median time can get before it
+// is flagged, as a fraction of the baseline.
+pub const SLOWDOWN_TOLERANCE: f64 = 0.1;
+
+// Summary of the times taken over several runs.
+#[derive(Clone, Copy, Debug)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_runs(runs: &[Duration]) -> Stats {
+        let mut runs = runs.to_vec();
+        runs.sort_unstable();
+
+        return Stats {
+            min: *runs.first().expect("should have at least 1 run"),
+            median: runs[runs.len() / 2],
+            max: *runs.last().expect("should have at least 1 run"),
+        };
+    }
+
+    // Whether this is slow enough compared to a baseline median to flag.
+    pub fn slower_than(&self, baseline: Duration) -> bool {
+        self.median.as_secs_f64() > baseline.as_secs_f64() * (1.0 + SLOWDOWN_TOLERANCE)
+    }
+}
+
+// Baseline timings depend on the machine, so they live next to the real
+// inputs rather than in the repo.
+pub fn baseline_path() -> PathBuf {
+    data::dir().join("bench_baseline.txt")
+}
+
+// Reads the median time of each puzzle from a baseline file, which has one
+// "<puzzle> <nanoseconds>" line per puzzle. A missing file is an empty
+// baseline.
+pub fn read_baseline(path: &Path) -> io::Result<HashMap<Puzzle, Duration>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(HashMap::new()),
+        Err(e) => return Err(e),
+    };
+
+    let mut baseline = HashMap::new();
+    for line in contents.lines() {
+        let entry = line
+            .split_once(' ')
+            .and_then(|(puzzle, nanos)| Some((puzzle.parse().ok()?, nanos.parse().ok()?)));
+        let Some((puzzle, nanos)) = entry else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("bad baseline line '{}'", line),
+            ));
+        };
+        baseline.insert(puzzle, Duration::from_nanos(nanos));
+    }
+
+    return Ok(baseline);
+}
+
+// Writes the median time of each puzzle to a baseline file, in puzzle order.
+pub fn write_baseline(path: &Path, baseline: &HashMap<Puzzle, Duration>) -> io::Result<()> {
+    let mut medians: Vec<_> = baseline.iter().collect();
+    medians.sort_by_key(|(puzzle, _)| (puzzle.day, puzzle.part as u8));
+
+    let contents: String = medians
+        .iter()
+        .map(|(puzzle, median)| format!("{} {}\n", puzzle, median.as_nanos()))
+        .collect();
+    fs::write(path, contents)
+}
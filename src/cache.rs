@@ -0,0 +1,105 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::answers::{escape, unescape};
+use crate::data;
+use crate::days::Day;
+use crate::{Answer, Part, Puzzle};
+
+// Source of every module besides the days' own, listed by the build script.
+// A change to any of them could change any answer, so it empties the whole
+// cache.
+const SHARED_SOURCES: &[&str] = include!(concat!(env!("OUT_DIR"), "/shared_sources.rs"));
+
+// Cached answers depend on the inputs, which aren't in the repo, so they live
+// next to them.
+pub fn dir() -> PathBuf {
+    data::dir().join("cache")
+}
+
+// 64-bit FNV-1a, which is plenty to tell inputs and versions apart and, unlike
+// the standard library's hasher, gives the same hash from one build to the
+// next.
+fn hash(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+const HASH_START: u64 = 0xcbf29ce484222325;
+
+// Where the answer to a puzzle for one input is cached, and the version of the
+// solution it's for.
+pub struct Key {
+    pub puzzle: Puzzle,
+    input: u64,
+    version: u64,
+}
+
+// Hashes each piece in turn, ending each in a 0 byte, which none of them
+// contain, so that moving the boundary between two pieces changes the hash.
+fn hash_all<'a>(pieces: impl IntoIterator<Item = &'a str>) -> u64 {
+    pieces
+        .into_iter()
+        .fold(HASH_START, |h, piece| hash(hash(h, piece.as_bytes()), &[0]))
+}
+
+impl Key {
+    // Key for solving a part of a day's puzzle, with the given (name, value)
+    // pairs overriding its parameters. Each input and set of parameters has
+    // its own cache file, and the answer in it only counts for the current
+    // source of the solution.
+    pub fn new(day: &Day, part: Part, input: &str, params: &[(String, i64)]) -> Key {
+        let mut params: Vec<String> = params
+            .iter()
+            .map(|(name, value)| format!("{}={}", name, value))
+            .collect();
+        params.sort();
+
+        return Key {
+            puzzle: Puzzle {
+                day: day.day,
+                part: part,
+            },
+            input: hash_all([params.join(",").as_str(), input]),
+            version: hash_all(
+                [day.source]
+                    .into_iter()
+                    .chain(SHARED_SOURCES.iter().copied()),
+            ),
+        };
+    }
+
+    fn path(&self) -> PathBuf {
+        dir().join(format!("{}_{:016x}.txt", self.puzzle, self.input))
+    }
+}
+
+// Cached answer for a key, if there is one for the current version. A cache
+// file that can't be read counts as a miss, as the answer can always be
+// worked out again.
+pub fn get(key: &Key) -> Option<Answer> {
+    let contents = fs::read_to_string(key.path()).ok()?;
+    let mut fields = contents.trim_end_matches('\n').splitn(3, ' ');
+    let (version, kind, answer) = (fields.next()?, fields.next()?, fields.next()?);
+    if u64::from_str_radix(version, 16).ok()? != key.version {
+        return None;
+    }
+    match kind {
+        "number" => Some(Answer::Number(answer.parse().ok()?)),
+        "text" => Some(Answer::Text(unescape(answer))),
+        _ => None,
+    }
+}
+
+// Caches the answer for a key, as one "<version> <number|text> <answer>"
+// line, replacing the answer from any older version.
+pub fn put(key: &Key, answer: &Answer) -> io::Result<()> {
+    fs::create_dir_all(dir())?;
+    let answer = match answer {
+        Answer::Number(n) => format!("number {}", n),
+        Answer::Text(s) => format!("text {}", escape(s)),
+    };
+    fs::write(key.path(), format!("{:016x} {}\n", key.version, answer))
+}
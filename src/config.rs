@@ -0,0 +1,503 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::{Part, Puzzle};
+
+// Project configuration, read from the directory the runner is started in.
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+// Which kind of input a day is being run on, which decides the parameter
+// values it gets from the config.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InputKind {
+    Sample,
+    Real,
+    // Any other input, e.g. one given with --input or a generated one.
+    Other,
+}
+
+// How a data file is named, with placeholders for the puzzle it's for:
+// {puzzle} (e.g. "14b"), {day}, {part}, and for samples {variant}, which is
+// "-N" for numbered samples and empty otherwise.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pattern(String);
+
+// Pieces of a pattern: literal text, or a placeholder's name.
+enum Piece<'a> {
+    Text(&'a str),
+    Placeholder(&'a str),
+}
+
+impl Pattern {
+    fn new(pattern: &str, placeholders: &[&str]) -> Result<Pattern, String> {
+        let pattern = Pattern(pattern.to_string());
+        let pieces = pattern.pieces()?;
+
+        let has = |name| {
+            pieces
+                .iter()
+                .any(|p| matches!(p, Piece::Placeholder(n) if *n == name))
+        };
+        for piece in &pieces {
+            if let Piece::Placeholder(name) = piece {
+                if !placeholders.contains(name) {
+                    return Err(format!("unknown placeholder {{{}}}", name));
+                }
+            }
+        }
+        let names_puzzle = has("puzzle") || (has("day") && has("part"));
+        if !names_puzzle {
+            return Err("should have {puzzle}, or {day} and {part}".to_string());
+        }
+        if placeholders.contains(&"variant") && !has("variant") {
+            return Err("should have {variant}".to_string());
+        }
+        return Ok(pattern);
+    }
+
+    fn pieces(&self) -> Result<Vec<Piece<'_>>, String> {
+        let mut pieces = Vec::new();
+        let mut rest = self.0.as_str();
+        while let Some(open) = rest.find('{') {
+            let close = rest[open..]
+                .find('}')
+                .ok_or("has a { without a }".to_string())?;
+            if open > 0 {
+                pieces.push(Piece::Text(&rest[..open]));
+            }
+            pieces.push(Piece::Placeholder(&rest[open + 1..open + close]));
+            rest = &rest[open + close + 1..];
+        }
+        if !rest.is_empty() {
+            pieces.push(Piece::Text(rest));
+        }
+        return Ok(pieces);
+    }
+
+    // File name for a puzzle and sample variant.
+    pub fn render(&self, puzzle: Puzzle, variant: Option<u32>) -> String {
+        let pieces = self.pieces().expect("pattern should have been checked");
+        pieces
+            .iter()
+            .map(|piece| match piece {
+                Piece::Text(s) => s.to_string(),
+                Piece::Placeholder("puzzle") => puzzle.to_string(),
+                Piece::Placeholder("day") => puzzle.day.to_string(),
+                Piece::Placeholder("part") => puzzle.part.to_string(),
+                Piece::Placeholder(_) => variant.map_or(String::new(), |n| format!("-{}", n)),
+            })
+            .collect()
+    }
+
+    // Puzzle and sample variant a file name is for, if it fits the pattern.
+    pub fn parse(&self, name: &str) -> Option<(Puzzle, Option<u32>)> {
+        // Takes the digits from the start of a string.
+        fn digits(s: &str) -> (&str, &str) {
+            s.split_at(s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len()))
+        }
+        fn part(s: &str) -> Option<(Part, &str)> {
+            match s.chars().next()? {
+                'a' => Some((Part::A, &s[1..])),
+                'b' => Some((Part::B, &s[1..])),
+                _ => None,
+            }
+        }
+
+        let (mut day, mut which_part, mut variant) = (None, None, None);
+        let mut rest = name;
+        for piece in self.pieces().ok()? {
+            match piece {
+                Piece::Text(s) => rest = rest.strip_prefix(s)?,
+                Piece::Placeholder("puzzle") => {
+                    let (n, after) = digits(rest);
+                    day = Some(n.parse().ok()?);
+                    let (p, after) = part(after)?;
+                    which_part = Some(p);
+                    rest = after;
+                }
+                Piece::Placeholder("day") => {
+                    let (n, after) = digits(rest);
+                    day = Some(n.parse().ok()?);
+                    rest = after;
+                }
+                Piece::Placeholder("part") => {
+                    let (p, after) = part(rest)?;
+                    which_part = Some(p);
+                    rest = after;
+                }
+                Piece::Placeholder(_) => {
+                    if let Some(after) = rest.strip_prefix('-') {
+                        let (n, after) = digits(after);
+                        variant = Some(n.parse().ok()?);
+                        rest = after;
+                    }
+                }
+            }
+        }
+
+        if !rest.is_empty() {
+            return None;
+        }
+        let puzzle = Puzzle {
+            day: day?,
+            part: which_part?,
+        };
+        return Some((puzzle, variant));
+    }
+}
+
+// Parameter values set for one day.
+#[derive(Clone, Debug, Default)]
+struct DayParams {
+    // For every input.
+    all: Vec<(String, i64)>,
+    sample: Vec<(String, i64)>,
+    real: Vec<(String, i64)>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    pub data_dir: PathBuf,
+    pub input: Pattern,
+    pub sample_input: Pattern,
+    pub sample_output: Pattern,
+    params: HashMap<u8, DayParams>,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            data_dir: PathBuf::from("data"),
+            input: Pattern("{puzzle}_input.txt".to_string()),
+            sample_input: Pattern("{puzzle}_sample-input{variant}.txt".to_string()),
+            sample_output: Pattern("{puzzle}_sample-output{variant}.txt".to_string()),
+            params: HashMap::new(),
+        }
+    }
+}
+
+// A value in the config file.
+enum Value {
+    Text(String),
+    Number(i64),
+}
+
+// Parses a value: a "quoted string", with \" and \\ escapes, or an integer,
+// which can have _ between digits.
+fn parse_value(s: &str) -> Result<Value, String> {
+    if let Some(quoted) = s.strip_prefix('"') {
+        let mut text = String::new();
+        let mut chars = quoted.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '"' => {
+                    return match chars.as_str().trim() {
+                        "" => Ok(Value::Text(text)),
+                        rest => Err(format!("unexpected '{}' after string", rest)),
+                    }
+                }
+                '\\' => match chars.next() {
+                    Some(c @ ('"' | '\\')) => text.push(c),
+                    _ => return Err("only \\\" and \\\\ escapes are supported".to_string()),
+                },
+                c => text.push(c),
+            }
+        }
+        return Err("string is missing its closing quote".to_string());
+    }
+
+    let digits = s.replace('_', "");
+    match digits.parse() {
+        Ok(n) if !s.starts_with('_') && !s.ends_with('_') => Ok(Value::Number(n)),
+        _ => Err(format!(
+            "expected a \"string\" or an integer, found '{}'",
+            s
+        )),
+    }
+}
+
+// Strips a comment from the end of a line, leaving any # inside a string.
+fn strip_comment(line: &str) -> &str {
+    let mut quoted = false;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            '#' if !quoted => return &line[..i],
+            _ => {}
+        }
+    }
+    return line;
+}
+
+impl Config {
+    // Parses a config file. It's a small subset of TOML: a [data] table with
+    // the data directory and file name patterns, and [dayN], [dayN.sample]
+    // and [dayN.real] tables of parameter values.
+    pub fn parse(contents: &str) -> Result<Config, String> {
+        let mut config = Config::default();
+        // The table being filled in: None for the top level, before any
+        // table header.
+        let mut table: Option<String> = None;
+
+        for (i, line) in contents.lines().enumerate() {
+            let error = |message: String| format!("line {}: {}", i + 1, message);
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(header) = line.strip_prefix('[') {
+                let name = header
+                    .strip_suffix(']')
+                    .ok_or_else(|| error("table header is missing its ]".to_string()))?
+                    .trim();
+                table = Some(name.to_string());
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| error(format!("expected key = value, found '{}'", line)))?;
+            let key = key.trim();
+            let value = parse_value(value.trim()).map_err(error)?;
+            config.set(table.as_deref(), key, value).map_err(error)?;
+        }
+
+        return Ok(config);
+    }
+
+    fn set(&mut self, table: Option<&str>, key: &str, value: Value) -> Result<(), String> {
+        let Some(table) = table else {
+            return Err(format!("'{}' should be in a table, e.g. [data]", key));
+        };
+
+        if table == "data" {
+            let Value::Text(text) = value else {
+                return Err(format!("data {} should be a string", key));
+            };
+            let pattern = |placeholders: &[&str]| {
+                Pattern::new(&text, placeholders).map_err(|e| format!("data {} {}", key, e))
+            };
+            match key {
+                "dir" => self.data_dir = PathBuf::from(&text),
+                "input" => self.input = pattern(&["puzzle", "day", "part"])?,
+                "sample_input" => {
+                    self.sample_input = pattern(&["puzzle", "day", "part", "variant"])?
+                }
+                "sample_output" => {
+                    self.sample_output = pattern(&["puzzle", "day", "part", "variant"])?
+                }
+                _ => return Err(format!(
+                    "unknown data setting '{}', expected dir, input, sample_input or sample_output",
+                    key
+                )),
+            }
+            return Ok(());
+        }
+
+        let (day, kind) = match table.split_once('.') {
+            Some((day, kind)) => (day, Some(kind)),
+            None => (table, None),
+        };
+        let day: u8 = day
+            .strip_prefix("day")
+            .and_then(|n| n.parse().ok())
+            .ok_or_else(|| format!("unknown table [{}], expected [data] or [dayN]", table))?;
+        let Value::Number(n) = value else {
+            return Err(format!("parameter '{}' should be an integer", key));
+        };
+
+        let params = self.params.entry(day).or_default();
+        let params = match kind {
+            None => &mut params.all,
+            Some("sample") => &mut params.sample,
+            Some("real") => &mut params.real,
+            Some(_) => {
+                return Err(format!(
+                    "unknown table [{}], expected [day{}], [day{}.sample] or [day{}.real]",
+                    table, day, day, day
+                ))
+            }
+        };
+        params.push((key.to_string(), n));
+        return Ok(());
+    }
+
+    // Reads the config file, if there is one. A missing file leaves every
+    // setting at its default.
+    pub fn read(path: &Path) -> Result<Config, String> {
+        match fs::read_to_string(path) {
+            Ok(contents) => {
+                Config::parse(&contents).map_err(|e| format!("{} {}", path.display(), e))
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(e) => Err(format!("could not read {}: {}", path.display(), e)),
+        }
+    }
+
+    // Parameter values the config sets for a day on a kind of input, as
+    // (name, value) pairs. Values for every input come first, so that those
+    // just for samples or the real input override them.
+    pub fn params(&self, day: u8, kind: InputKind) -> Vec<(String, i64)> {
+        let Some(params) = self.params.get(&day) else {
+            return Vec::new();
+        };
+
+        let mut values = params.all.clone();
+        match kind {
+            InputKind::Sample => values.extend(params.sample.iter().cloned()),
+            InputKind::Real => values.extend(params.real.iter().cloned()),
+            InputKind::Other => {}
+        }
+        return values;
+    }
+}
+
+static CONFIG: OnceLock<Result<Config, String>> = OnceLock::new();
+
+// The config from the config file, read the first time it's needed.
+pub fn load() -> Result<&'static Config, String> {
+    CONFIG
+        .get_or_init(|| Config::read(Path::new(CONFIG_FILE)))
+        .as_ref()
+        .map_err(String::clone)
+}
+
+// Like load, for code that runs after the config has been loaded without
+// error.
+pub fn get() -> &'static Config {
+    load().unwrap_or_else(|e| panic!("config should have loaded: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn puzzle(s: &str) -> Puzzle {
+        s.parse().unwrap()
+    }
+
+    fn params(pairs: &[(&str, i64)]) -> Vec<(String, i64)> {
+        pairs.iter().map(|&(k, v)| (k.to_string(), v)).collect()
+    }
+
+    #[test]
+    fn empty_config_is_the_default() {
+        let config = Config::parse("# nothing here\n\n").unwrap();
+        assert_eq!(config.data_dir, PathBuf::from("data"));
+        assert_eq!(config.input, Config::default().input);
+        assert!(config.params(15, InputKind::Sample).is_empty());
+    }
+
+    #[test]
+    fn data_settings() {
+        let config = Config::parse(
+            "[data]\n\
+             dir = \"puzzles\"  # comment\n\
+             input = \"day{day}/{part}.txt\"\n\
+             sample_input = \"{puzzle}#{variant}.in\"\n",
+        )
+        .unwrap();
+        assert_eq!(config.data_dir, PathBuf::from("puzzles"));
+        assert_eq!(config.input.render(puzzle("7b"), None), "day7/b.txt");
+        assert_eq!(
+            config.sample_input.render(puzzle("7b"), Some(2)),
+            "7b#-2.in"
+        );
+        assert_eq!(config.sample_input.render(puzzle("7b"), None), "7b#.in");
+    }
+
+    #[test]
+    fn params_for_each_kind_of_input() {
+        let config = Config::parse(
+            "[day15]\n\
+             target = 2_000_000\n\
+             [day15.sample]\n\
+             target = 10\n\
+             [day15.real]\n\
+             coord_max = -4\n",
+        )
+        .unwrap();
+        assert_eq!(
+            config.params(15, InputKind::Sample),
+            params(&[("target", 2000000), ("target", 10)])
+        );
+        assert_eq!(
+            config.params(15, InputKind::Real),
+            params(&[("target", 2000000), ("coord_max", -4)])
+        );
+        assert_eq!(
+            config.params(15, InputKind::Other),
+            params(&[("target", 2000000)])
+        );
+        assert!(config.params(16, InputKind::Real).is_empty());
+    }
+
+    #[test]
+    fn strings_can_escape_quotes_and_hold_comments() {
+        let config = Config::parse("[data]\ndir = \"a \\\"b\\\" \\\\ #c\" # d\n").unwrap();
+        assert_eq!(config.data_dir, PathBuf::from("a \"b\" \\ #c"));
+    }
+
+    #[test]
+    fn errors_name_the_line() {
+        let error = |contents: &str| Config::parse(contents).unwrap_err();
+        assert_eq!(
+            error("dir = \"data\"\n"),
+            "line 1: 'dir' should be in a table, e.g. [data]"
+        );
+        assert!(error("[data]\n\ndir\n").starts_with("line 3: expected key = value"));
+        assert!(error("[data\n").starts_with("line 1: table header"));
+        assert!(error("[data]\ndir = 3\n").contains("should be a string"));
+        assert!(error("[data]\ndir = \"x\n").contains("closing quote"));
+        assert!(error("[data]\ndir = \"x\" y\n").contains("after string"));
+        assert!(error("[data]\nfiles = \"x\"\n").contains("unknown data setting"));
+        assert!(error("[day1]\nn = \"x\"\n").contains("should be an integer"));
+        assert!(error("[day1]\nn = 1_\n").contains("found '1_'"));
+        assert!(error("[day1.other]\nn = 1\n").contains("unknown table"));
+        assert!(error("[dayx]\nn = 1\n").contains("unknown table"));
+    }
+
+    #[test]
+    fn patterns_need_to_name_the_puzzle() {
+        let error = |pattern: &str| {
+            Config::parse(&format!("[data]\ninput = \"{}\"\n", pattern)).unwrap_err()
+        };
+        assert!(error("{day}_input.txt").contains("should have {puzzle}"));
+        assert!(error("{puzzle}_{name}.txt").contains("unknown placeholder {name}"));
+        assert!(error("{puzzle").contains("without a }"));
+        let error = Config::parse("[data]\nsample_input = \"{puzzle}.txt\"\n").unwrap_err();
+        assert!(error.contains("should have {variant}"));
+    }
+
+    #[test]
+    fn patterns_parse_the_names_they_render() {
+        let config = Config::default();
+        assert_eq!(
+            config.sample_input.parse("14b_sample-input-3.txt"),
+            Some((puzzle("14b"), Some(3)))
+        );
+        assert_eq!(
+            config.sample_input.parse("14b_sample-input.txt"),
+            Some((puzzle("14b"), None))
+        );
+        assert_eq!(
+            config.input.parse("6a_input.txt"),
+            Some((puzzle("6a"), None))
+        );
+        assert_eq!(config.input.parse("6c_input.txt"), None);
+        assert_eq!(config.input.parse("6a_input.txt.bak"), None);
+
+        let pattern = Pattern::new("day{day}/{part}.txt", &["puzzle", "day", "part"]).unwrap();
+        for name in ["3a", "25b"] {
+            let rendered = pattern.render(puzzle(name), None);
+            assert_eq!(pattern.parse(&rendered), Some((puzzle(name), None)));
+        }
+    }
+}
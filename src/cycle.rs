@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// Where a simulation started repeating itself: the state at step start + len
+// is the same as the one at step start, and so on forever after.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub len: usize,
+}
+
+// A metric taken at each step of a simulation, along with the cycle the
+// simulation fell into, if it was found.
+#[derive(Clone, Debug)]
+pub struct History {
+    // Metric at each step that was run, from the initial state at step 0.
+    // With a cycle, this runs up to and including the step that repeated.
+    metrics: Vec<i64>,
+    cycle: Option<Cycle>,
+}
+
+impl History {
+    pub fn cycle(&self) -> Option<Cycle> {
+        self.cycle
+    }
+
+    pub fn metrics(&self) -> &[i64] {
+        &self.metrics
+    }
+
+    // Metric at any step, following the cycle past the steps that were run.
+    // This assumes the metric changes by the same amount each time around the
+    // cycle, like the height of the tower in day 17. None if the step wasn't
+    // run and no cycle was found.
+    pub fn at(&self, step: usize) -> Option<i64> {
+        if let Some(&metric) = self.metrics.get(step) {
+            return Some(metric);
+        }
+
+        let cycle = self.cycle?;
+        let per_cycle = self.metrics[cycle.start + cycle.len] - self.metrics[cycle.start];
+        let cycles = (step - cycle.start) / cycle.len;
+        let offset = (step - cycle.start) % cycle.len;
+
+        return Some(self.metrics[cycle.start + offset] + cycles as i64 * per_cycle);
+    }
+}
+
+// Runs a simulation from an initial state for up to a number of steps,
+// stopping early when it comes back to a state it has been in before. The key
+// of a state should capture everything that decides how the simulation goes on
+// from it, and is given the step number for simulations that depend on it.
+pub fn simulate<S, K: Eq + Hash>(
+    mut state: S,
+    steps: usize,
+    mut key: impl FnMut(&S, usize) -> K,
+    mut metric: impl FnMut(&S) -> i64,
+    mut advance: impl FnMut(&mut S, usize),
+) -> History {
+    // seen maps the key of each state to the step it was seen on.
+    let mut seen = HashMap::new();
+    let mut metrics = Vec::new();
+    let mut cycle = None;
+
+    let mut step = 0;
+    loop {
+        metrics.push(metric(&state));
+
+        let state_key = key(&state, step);
+        if let Some(&start) = seen.get(&state_key) {
+            cycle = Some(Cycle {
+                start: start,
+                len: step - start,
+            });
+            break;
+        }
+        if step == steps {
+            break;
+        }
+
+        seen.insert(state_key, step);
+        advance(&mut state, step);
+        step += 1;
+    }
+
+    return History {
+        metrics: metrics,
+        cycle: cycle,
+    };
+}
@@ -0,0 +1,89 @@
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use crate::config;
+use crate::Puzzle;
+
+// Directory with the inputs, samples, and everything else that depends on
+// them, as set in the config.
+pub fn dir() -> PathBuf {
+    config::get().data_dir.clone()
+}
+
+// A sample input and its expected output. Puzzles with more than one sample
+// number them, e.g. data/6a_sample-input-3.txt and data/6a_sample-output-3.txt
+// with the default file names.
+pub struct Sample {
+    pub puzzle: Puzzle,
+    pub variant: Option<u32>,
+    pub input: PathBuf,
+    pub output: PathBuf,
+}
+
+impl Sample {
+    // Name of the input file without its extension, e.g. "6a_sample-input-3".
+    pub fn file_stem(&self) -> String {
+        self.input
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    }
+
+    // Short name used when reporting on the sample, e.g. "6a sample 3".
+    pub fn name(&self) -> String {
+        match self.variant {
+            Some(n) => format!("{} sample {}", self.puzzle, n),
+            None => format!("{} sample", self.puzzle),
+        }
+    }
+}
+
+// Path to the real puzzle input, e.g. data/14b_input.txt.
+pub fn input_path(puzzle: Puzzle) -> PathBuf {
+    let config = config::get();
+    config.data_dir.join(config.input.render(puzzle, None))
+}
+
+// Paths of a sample's input and output files, whether or not they exist.
+pub fn sample(puzzle: Puzzle, variant: Option<u32>) -> Sample {
+    let config = config::get();
+    return Sample {
+        puzzle: puzzle,
+        variant: variant,
+        input: config
+            .data_dir
+            .join(config.sample_input.render(puzzle, variant)),
+        output: config
+            .data_dir
+            .join(config.sample_output.render(puzzle, variant)),
+    };
+}
+
+// Finds every sample input in the data directory, ordered by puzzle and then
+// variant number.
+pub fn all_samples() -> io::Result<Vec<Sample>> {
+    let mut samples = Vec::new();
+
+    for entry in fs::read_dir(dir())? {
+        let name = entry?.file_name();
+        let Some(name) = name.to_str() else {
+            continue;
+        };
+        let Some((puzzle, variant)) = config::get().sample_input.parse(name) else {
+            continue;
+        };
+
+        samples.push(sample(puzzle, variant));
+    }
+
+    samples.sort_by_key(|s| (s.puzzle.day, s.puzzle.part as u8, s.variant));
+    return Ok(samples);
+}
+
+// Finds every sample input for the puzzle, ordered by variant number.
+pub fn samples(puzzle: Puzzle) -> io::Result<Vec<Sample>> {
+    let mut samples = all_samples()?;
+    samples.retain(|s| s.puzzle == puzzle);
+    return Ok(samples);
+}
@@ -0,0 +1,70 @@
+use std::collections::BinaryHeap;
+
+use crate::parse::{end_of_input, lines};
+use crate::rng::Rng;
+use crate::{Answer, Context, ParseError, Part, Solution};
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    // Total calories carried by each elf.
+    type Input = Vec<u32>;
+
+    fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+        let mut elves = Vec::new();
+        let mut sum = 0;
+
+        for line in lines(input) {
+            if line.text.is_empty() {
+                elves.push(sum);
+                sum = 0;
+            } else {
+                let cal: u32 = line.parse(line.text.trim(), "a calorie value")?;
+                sum += cal;
+            }
+        }
+
+        elves.push(sum);
+        return Ok(elves);
+    }
+
+    fn part_a(elves: &Vec<u32>, _: &Context) -> Answer {
+        elves
+            .iter()
+            .copied()
+            .max()
+            .expect("should have at least 1 elf from input")
+            .into()
+    }
+
+    fn part_b(elves: &Vec<u32>, _: &Context) -> Answer {
+        let mut heap: BinaryHeap<u32> = elves.iter().copied().collect();
+
+        let mut sum = 0;
+        for _ in 0..3 {
+            sum += heap.pop().expect("should have at least 3 elves from input");
+        }
+
+        return sum.into();
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        // One elf per size, and at least the 3 that part B adds up.
+        let elves: Vec<String> = (0..size.max(3))
+            .map(|_| {
+                let items = rng.range(1..=15);
+                (0..items)
+                    .map(|_| format!("{}\n", rng.range(1000..=60000)))
+                    .collect()
+            })
+            .collect();
+        return Some(elves.join("\n"));
+    }
+
+    fn validate(part: Part, raw: &str, elves: &Vec<u32>) -> Vec<ParseError> {
+        if part == Part::B && elves.len() < 3 {
+            return vec![end_of_input(raw, "at least 3 elves")];
+        }
+        return Vec::new();
+    }
+}
@@ -0,0 +1,143 @@
+use crate::parse::lines;
+use crate::rng::Rng;
+use crate::scan::template;
+use crate::{Answer, Context, Param, ParseError, Solution};
+
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+fn output_beam(crt: &mut String, cycle: i32, pos: i32) {
+    let beam_pos = cycle % 40;
+    if beam_pos.abs_diff(pos) <= 1 {
+        crt.push('#');
+    } else {
+        crt.push('.');
+    }
+    if beam_pos == 39 {
+        crt.push('\n');
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Instruction>;
+
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "cycles",
+            default: 240,
+            min: 0,
+            max: i32::MAX as i64,
+            description: "Cycles drawn on the CRT in part B",
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+        lines(input)
+            .map(|line| match line.text.split_whitespace().next() {
+                Some("noop") => {
+                    let [] = template(line, line.text, "noop")?;
+                    Ok(Instruction::Noop)
+                }
+                Some("addx") => {
+                    // The operand is whatever follows the first word, which
+                    // is already known to be a whole word.
+                    let [op] = template(line, line.text, "addx{}")?;
+                    Ok(Instruction::Addx(line.parse(op, "an addx operand")?))
+                }
+                _ => Err(line.error(line.text.trim(), "a noop or addx instruction")),
+            })
+            .collect()
+    }
+
+    fn part_a(program: &Vec<Instruction>, _: &Context) -> Answer {
+        let mut x = 1;
+        let mut cycle = 1;
+        let mut checkpoint = 20;
+        let mut signal = 0;
+
+        for inst in program {
+            if cycle > 220 {
+                break;
+            }
+
+            if cycle >= checkpoint {
+                signal += checkpoint * x;
+                checkpoint += 40;
+            }
+
+            match inst {
+                Instruction::Noop => {
+                    cycle += 1;
+                }
+                Instruction::Addx(op) => {
+                    cycle += 1;
+
+                    if cycle >= checkpoint {
+                        signal += checkpoint * x;
+                        checkpoint += 40;
+                    }
+
+                    cycle += 1;
+                    x += op;
+                }
+            }
+        }
+
+        return signal.into();
+    }
+
+    fn part_b(program: &Vec<Instruction>, ctx: &Context) -> Answer {
+        let mut program = program.iter();
+        let mut crt = String::new();
+        let mut x = 1;
+        let mut add_op = None;
+
+        for i in 0..ctx.param("cycles") as i32 {
+            match add_op {
+                None => {
+                    // Stop drawing if the program ends early.
+                    let Some(inst) = program.next() else {
+                        break;
+                    };
+
+                    if let Instruction::Addx(op) = inst {
+                        add_op = Some(op);
+                    }
+
+                    output_beam(&mut crt, i, x);
+                }
+                Some(data) => {
+                    output_beam(&mut crt, i, x);
+                    x += data;
+                    add_op = None;
+                }
+            };
+        }
+
+        return crt.trim_end().to_string().into();
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        // At least size instructions, and enough to last the 240 cycles of
+        // part B. The sprite stays on the screen.
+        let mut program = String::new();
+        let (mut x, mut cycles, mut count) = (1, 0, 0);
+        while cycles < 240 || count < size {
+            let next = (x + rng.range(-10..=10)).clamp(0, 39);
+            if next == x || rng.chance(0.3) {
+                program += "noop\n";
+                cycles += 1;
+            } else {
+                program += &format!("addx {}\n", next - x);
+                x = next;
+                cycles += 2;
+            }
+            count += 1;
+        }
+        return Some(program);
+    }
+}
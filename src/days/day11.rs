@@ -0,0 +1,375 @@
+use std::vec::Vec;
+
+use crate::parse::{end_of_input, lines, Line};
+use crate::rng::Rng;
+use crate::scan::{field, template};
+use crate::{Answer, Context, Param, ParseError, Part, Solution};
+
+enum Operator {
+    Add,
+    Multiply,
+}
+
+enum Operand {
+    Old,
+    Number(u64),
+}
+
+struct Operation {
+    operator: Operator,
+    operand: Operand,
+}
+
+impl Operation {
+    fn from(line: Line, s: &str) -> Result<Operation, ParseError> {
+        let [operator, operand] = template(line, s, "new = old {} {}")?;
+
+        return Ok(Operation {
+            operator: match operator {
+                "+" => Operator::Add,
+                "*" => Operator::Multiply,
+                _ => return Err(line.error(operator, "an operator + or *")),
+            },
+            operand: if operand == "old" {
+                Operand::Old
+            } else {
+                Operand::Number(line.parse(operand, "\"old\" or a number")?)
+            },
+        });
+    }
+
+    fn apply(&self, val: u64) -> u64 {
+        let o = match self.operand {
+            Operand::Old => val,
+            Operand::Number(n) => n,
+        };
+
+        return match self.operator {
+            Operator::Add => val + o,
+            Operator::Multiply => val * o,
+        };
+    }
+
+    // Like apply, but None if the result doesn't fit in a u64.
+    fn checked_apply(&self, val: u64) -> Option<u64> {
+        let o = match self.operand {
+            Operand::Old => val,
+            Operand::Number(n) => n,
+        };
+
+        return match self.operator {
+            Operator::Add => val.checked_add(o),
+            Operator::Multiply => val.checked_mul(o),
+        };
+    }
+}
+
+pub struct Monkey {
+    items: Vec<u64>,
+    operation: Operation,
+    test: u64,
+    true_target: usize,
+    false_target: usize,
+}
+
+// Runs the given number of rounds, applying `relief` to each worry level after
+// inspection, and returns the level of monkey business.
+fn monkey_business(monkeys: &[Monkey], rounds: usize, relief: impl Fn(u64) -> u64) -> usize {
+    let mut items: Vec<Vec<u64>> = monkeys.iter().map(|m| m.items.clone()).collect();
+    let mut inspects = vec![0; monkeys.len()];
+
+    // Run rounds on monkeys.
+    for _ in 0..rounds {
+        for (i, m) in monkeys.iter().enumerate() {
+            let held = std::mem::take(&mut items[i]);
+            inspects[i] += held.len();
+
+            for v in held {
+                let new_val = relief(m.operation.apply(v));
+                let target_num = if new_val.is_multiple_of(m.test) {
+                    m.true_target
+                } else {
+                    m.false_target
+                };
+                items[target_num].push(new_val);
+            }
+        }
+    }
+
+    // Find top 2 active monkeys to calculate monkey business.
+    inspects.select_nth_unstable_by_key(1, |&v| -(v as i64));
+    return inspects[0] * inspects[1];
+}
+
+// Part B without needing the tests to be distinct primes: each item is kept
+// as its worry level modulo each monkey's test, which is all the tests look
+// at.
+fn monkey_business_by_residues(monkeys: &[Monkey], rounds: usize) -> usize {
+    let residues = |v: u64| -> Vec<u64> { monkeys.iter().map(|m| v % m.test).collect() };
+    let mut items: Vec<Vec<Vec<u64>>> = monkeys
+        .iter()
+        .map(|m| m.items.iter().map(|&v| residues(v)).collect())
+        .collect();
+    let mut inspects = vec![0; monkeys.len()];
+
+    for _ in 0..rounds {
+        for (i, m) in monkeys.iter().enumerate() {
+            for item in std::mem::take(&mut items[i]) {
+                inspects[i] += 1;
+
+                let item: Vec<u64> = item
+                    .iter()
+                    .zip(monkeys)
+                    .map(|(&r, other)| {
+                        let o = match m.operation.operand {
+                            Operand::Old => r,
+                            Operand::Number(n) => n % other.test,
+                        };
+                        match m.operation.operator {
+                            Operator::Add => (r + o) % other.test,
+                            Operator::Multiply => r * o % other.test,
+                        }
+                    })
+                    .collect();
+                let target_num = if item[i] == 0 {
+                    m.true_target
+                } else {
+                    m.false_target
+                };
+                items[target_num].push(item);
+            }
+        }
+    }
+
+    inspects.sort_unstable();
+    return inspects.iter().rev().take(2).product();
+}
+
+// Primes for the monkeys' tests. Part B relies on each monkey testing by a
+// different prime, and on their product being below 2^32, so that squaring a
+// worry level can't overflow.
+const PRIMES: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+// Whether part A's worry levels stay within a u64. Nothing keeps them down
+// there apart from dividing by 3.
+fn fits_part_a(monkeys: &[Monkey]) -> bool {
+    let mut items: Vec<Vec<u64>> = monkeys.iter().map(|m| m.items.clone()).collect();
+
+    for _ in 0..20 {
+        for (i, m) in monkeys.iter().enumerate() {
+            for v in std::mem::take(&mut items[i]) {
+                let Some(new_val) = m.operation.checked_apply(v) else {
+                    return false;
+                };
+                let new_val = new_val / 3;
+                let target_num = if new_val.is_multiple_of(m.test) {
+                    m.true_target
+                } else {
+                    m.false_target
+                };
+                items[target_num].push(new_val);
+            }
+        }
+    }
+
+    return true;
+}
+
+// Writes out a monkey as it appears in the puzzle input.
+fn describe(i: usize, monkey: &Monkey) -> String {
+    let items: Vec<String> = monkey.items.iter().map(u64::to_string).collect();
+    let operator = match monkey.operation.operator {
+        Operator::Add => "+",
+        Operator::Multiply => "*",
+    };
+    let operand = match monkey.operation.operand {
+        Operand::Old => String::from("old"),
+        Operand::Number(n) => n.to_string(),
+    };
+
+    return format!(
+        "Monkey {}:\n  Starting items: {}\n  Operation: new = old {} {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+        i,
+        items.join(", "),
+        operator,
+        operand,
+        monkey.test,
+        monkey.true_target,
+        monkey.false_target
+    );
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey>;
+
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "rounds",
+            default: 10000,
+            min: 0,
+            max: 10000000,
+            description: "Rounds run in part B",
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Vec<Monkey>, ParseError> {
+        let mut lines = lines(input);
+        let mut monkeys = Vec::new();
+        // Where each throw target is, to check once every monkey is known.
+        let mut targets = Vec::new();
+
+        // Read "Monkey n:" line/check for end of input.
+        while let Some(line) = lines.next() {
+            let [_] = template(line, line.text, "Monkey {}:")?;
+            let mut next_line =
+                |expected| lines.next().ok_or_else(|| end_of_input(input, expected));
+
+            // Read and parse "Starting items: ..." line.
+            let line = next_line("starting items")?;
+            let items = field(line, line.text, "Starting items")?
+                .split(',')
+                .map(|s| line.parse(s.trim(), "an item worry level"))
+                .collect::<Result<Vec<u64>, _>>()?;
+
+            // Read and parse "Operation: ..." line.
+            let line = next_line("an operation")?;
+            let operation = Operation::from(line, field(line, line.text, "Operation")?)?;
+
+            // Read and parse "Test: ..." line.
+            let line = next_line("a test")?;
+            let [test_token] = template(line, field(line, line.text, "Test")?, "divisible by {}")?;
+            let test = line.parse(test_token, "a divisor")?;
+            if test == 0 {
+                return Err(line.error(test_token, "a divisor of at least 1"));
+            }
+
+            // Read and parse "If true: ..." line.
+            let line = next_line("a true target")?;
+            let [true_token] = template(
+                line,
+                field(line, line.text, "If true")?,
+                "throw to monkey {}",
+            )?;
+            let true_target = line.parse(true_token, "a monkey number")?;
+            targets.push((line, true_token, true_target));
+
+            // Read and parse "If false: ..." line.
+            let line = next_line("a false target")?;
+            let [false_token] = template(
+                line,
+                field(line, line.text, "If false")?,
+                "throw to monkey {}",
+            )?;
+            let false_target = line.parse(false_token, "a monkey number")?;
+            targets.push((line, false_token, false_target));
+
+            // Create Monkey and add to vec.
+            monkeys.push(Monkey {
+                items: items,
+                operation: operation,
+                test: test,
+                true_target: true_target,
+                false_target: false_target,
+            });
+
+            // Skip blank separator line.
+            lines.next();
+        }
+
+        // Monkey business is the product of the 2 busiest monkeys.
+        if monkeys.len() < 2 {
+            return Err(end_of_input(input, "another monkey"));
+        }
+        for (line, token, target) in targets {
+            if target >= monkeys.len() {
+                return Err(line.error(token, &format!("a monkey number below {}", monkeys.len())));
+            }
+        }
+
+        return Ok(monkeys);
+    }
+
+    fn part_a(monkeys: &Vec<Monkey>, _: &Context) -> Answer {
+        monkey_business(monkeys, 20, |v| v / 3).into()
+    }
+
+    fn part_b(monkeys: &Vec<Monkey>, ctx: &Context) -> Answer {
+        // NOTE: This approach exploits a property of the input that isn't
+        // explicitly listed in the problem - each monkey's divisibily test is a
+        // distinct prime number, hence we can store modulos of each worry level
+        // instead of the raw worry level to avoid integer overflow.
+        let modulo: u64 = monkeys.iter().map(|m| m.test).product();
+
+        let rounds = ctx.param("rounds") as usize;
+        monkey_business(monkeys, rounds, |v| v % modulo).into()
+    }
+
+    fn reference(part: Part, monkeys: &Vec<Monkey>, ctx: &Context) -> Option<Answer> {
+        match part {
+            Part::A => None,
+            Part::B => {
+                let rounds = ctx.param("rounds") as usize;
+                Some(monkey_business_by_residues(monkeys, rounds).into())
+            }
+        }
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        // There's a prime for each monkey, and size scales how many items
+        // they start with. With fewer than 4 monkeys, items keep coming back
+        // to the one that squares them, and part A's worry levels overflow
+        // however the rest turns out.
+        let num_monkeys = size.clamp(4, PRIMES.len());
+
+        loop {
+            let mut primes = PRIMES.to_vec();
+            rng.shuffle(&mut primes);
+            // Like the real inputs, one monkey squares the worry level.
+            let squarer = rng.index(num_monkeys);
+
+            let monkeys: Vec<Monkey> = (0..num_monkeys)
+                .map(|i| {
+                    let num_items = rng.range(1..=size as i64 / 2 + 2);
+                    let items = (0..num_items).map(|_| rng.range(50..=99) as u64).collect();
+                    let operation = if i == squarer {
+                        Operation {
+                            operator: Operator::Multiply,
+                            operand: Operand::Old,
+                        }
+                    } else if rng.chance(0.25) {
+                        Operation {
+                            operator: Operator::Multiply,
+                            operand: Operand::Number(rng.range(2..=19) as u64),
+                        }
+                    } else {
+                        Operation {
+                            operator: Operator::Add,
+                            operand: Operand::Number(rng.range(1..=8) as u64),
+                        }
+                    };
+
+                    let mut others: Vec<usize> = (0..num_monkeys).filter(|&j| j != i).collect();
+                    rng.shuffle(&mut others);
+                    Monkey {
+                        items: items,
+                        operation: operation,
+                        test: primes[i],
+                        true_target: others[0],
+                        false_target: others[others.len() - 1],
+                    }
+                })
+                .collect();
+
+            if fits_part_a(&monkeys) {
+                let described: Vec<String> = monkeys
+                    .iter()
+                    .enumerate()
+                    .map(|(i, monkey)| describe(i, monkey))
+                    .collect();
+                return Some(described.join("\n"));
+            }
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+
+use crate::grid::{to_point, Grid};
+use crate::parse::{end_of_input, lines};
+use crate::rng::Rng;
+use crate::search::{astar, bfs, Graph};
+use crate::{Answer, Context, ParseError, Part, Solution};
+
+fn find(map: &Grid<u8>, target: u8) -> (usize, usize) {
+    map.position(|&cell| cell == target)
+        .unwrap_or_else(|| panic!("map should have target '{}'", target as char))
+}
+
+fn get_elev(map: &Grid<u8>, loc: &(usize, usize)) -> u8 {
+    match map[*loc] {
+        b'S' => b'a',
+        b'E' => b'z',
+        v => v,
+    }
+}
+
+// The hill as a graph of locations, where each step can climb at most 1.
+struct Hill<'a> {
+    map: &'a Grid<u8>,
+    end: (usize, usize),
+}
+
+impl Graph for Hill<'_> {
+    type Node = (usize, usize);
+
+    fn neighbours(&self, loc: &(usize, usize)) -> Vec<((usize, usize), u64)> {
+        let cur_elev = get_elev(self.map, loc);
+        self.map
+            .neighbours_4(*loc)
+            .filter(|new_loc| get_elev(self.map, new_loc) <= cur_elev + 1)
+            .map(|new_loc| (new_loc, 1))
+            .collect()
+    }
+
+    // Each step gets at most 1 closer to the end.
+    fn heuristic(&self, loc: &(usize, usize)) -> u64 {
+        to_point(*loc).manhattan(to_point(self.end))
+    }
+}
+
+// A random path between two locations that never crosses itself, found by
+// walking at random and cutting out each loop as it's made.
+fn loop_erased_walk(
+    map: &Grid<u8>,
+    start: (usize, usize),
+    end: (usize, usize),
+    rng: &mut Rng,
+) -> Vec<(usize, usize)> {
+    let mut path = vec![start];
+    let mut index = HashMap::from([(start, 0)]);
+
+    let mut pos = start;
+    while pos != end {
+        let neighbours: Vec<(usize, usize)> = map.neighbours_4(pos).collect();
+        pos = *rng.choose(&neighbours);
+        match index.get(&pos) {
+            Some(&i) => {
+                for erased in path.drain(i + 1..) {
+                    index.remove(&erased);
+                }
+            }
+            None => {
+                index.insert(pos, path.len());
+                path.push(pos);
+            }
+        }
+    }
+
+    return path;
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    // Map of elevations.
+    type Input = Grid<u8>;
+
+    fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+        let map = Grid::parse(input, |line, i, c| {
+            if c.is_ascii_lowercase() || c == 'S' || c == 'E' {
+                Ok(c as u8)
+            } else {
+                Err(line.error_at_char(i, "an elevation a-z, S or E"))
+            }
+        })?;
+
+        for (target, expected) in [(b'S', "a start S"), (b'E', "an end E")] {
+            if map.position(|&cell| cell == target).is_none() {
+                return Err(end_of_input(input, expected));
+            }
+        }
+
+        return Ok(map);
+    }
+
+    fn part_a(map: &Grid<u8>, ctx: &Context) -> Answer {
+        let start = find(map, b'S');
+        let end = find(map, b'E');
+
+        let hill = Hill { map: map, end: end };
+        let search = astar(&hill, [start], |&loc| loc == end);
+        ctx.report_search(search.stats());
+        search
+            .path()
+            .expect("should have found solution")
+            .steps()
+            .into()
+    }
+
+    fn part_b(map: &Grid<u8>, ctx: &Context) -> Answer {
+        let end = find(map, b'E');
+        let starts = map
+            .iter()
+            .filter(|&(_, &elev)| elev == b'a' || elev == b'S')
+            .map(|(loc, _)| loc);
+
+        let hill = Hill { map: map, end: end };
+        let search = bfs(&hill, starts, |&loc| loc == end);
+        ctx.report_search(search.stats());
+        search
+            .path()
+            .expect("should have found solution")
+            .steps()
+            .into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        // The real maps are about 4 times as wide as they are tall.
+        let rows = size.max(5);
+        let cols = rows * 4;
+
+        let mut map = Grid::new(rows, cols, b'a');
+        let start = (rng.index(rows), 0);
+        let path = loop {
+            let end = (rng.index(rows), cols / 2 + rng.index(cols / 2));
+            let path = loop_erased_walk(&map, start, end, rng);
+            // The path needs a step for each elevation from a to z.
+            if path.len() > 26 {
+                break path;
+            }
+        };
+
+        // Everywhere off the path is random, but the path climbs steadily so
+        // that the end can always be reached.
+        for pos in map.positions().collect::<Vec<_>>() {
+            map[pos] = b'a' + rng.below(26) as u8;
+        }
+        for (i, &pos) in path.iter().enumerate() {
+            map[pos] = b'a' + (i * 25 / (path.len() - 1)) as u8;
+        }
+        map[start] = b'S';
+        map[path[path.len() - 1]] = b'E';
+
+        return Some(map.render(|&cell| cell as char));
+    }
+
+    fn validate(part: Part, raw: &str, map: &Grid<u8>) -> Vec<ParseError> {
+        let end = find(map, b'E');
+        let starts: Vec<(usize, usize)> = match part {
+            Part::A => vec![find(map, b'S')],
+            Part::B => map
+                .iter()
+                .filter(|&(_, &elev)| elev == b'a' || elev == b'S')
+                .map(|(loc, _)| loc)
+                .collect(),
+        };
+
+        let hill = Hill { map: map, end: end };
+        if bfs(&hill, starts, |&loc| loc == end).path().is_some() {
+            return Vec::new();
+        }
+        let expected = match part {
+            Part::A => "an end E that can be climbed to from S",
+            Part::B => "an end E that can be climbed to from an a or S",
+        };
+        let line = lines(raw).nth(end.0).expect("end should be on a line");
+        return vec![line.error_at_char(end.1, expected)];
+    }
+}
@@ -0,0 +1,273 @@
+use std::borrow::Borrow;
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::rc::Rc;
+use std::vec::Vec;
+
+use crate::parse::{end_of_input, lines, Line};
+use crate::rng::Rng;
+use crate::{Answer, Context, ParseError, Part, Solution};
+
+#[derive(Eq, PartialEq)]
+pub enum PacketValue {
+    List(Vec<Rc<PacketValue>>),
+    Integer(u32),
+}
+
+impl PartialOrd for PacketValue {
+    fn partial_cmp(&self, right: &Self) -> Option<Ordering> {
+        Some(self.cmp(right))
+    }
+}
+
+impl Ord for PacketValue {
+    fn cmp(&self, right: &Self) -> Ordering {
+        match self {
+            PacketValue::List(ll) => match right {
+                PacketValue::List(rl) => {
+                    for i in 0.. {
+                        let item_l = ll.get(i);
+                        let item_r = rl.get(i);
+
+                        if item_l.is_none() && item_r.is_none() {
+                            return Ordering::Equal;
+                        }
+
+                        if item_l.is_none() && item_r.is_some() {
+                            return Ordering::Less;
+                        }
+
+                        if item_l.is_some() && item_r.is_none() {
+                            return Ordering::Greater;
+                        }
+
+                        // Both item_l and item_r are Some.
+                        let val_l = item_l.unwrap();
+                        let val_r = item_r.unwrap();
+
+                        let val_cmp = val_l.cmp(val_r);
+                        if val_cmp != Ordering::Equal {
+                            return val_cmp;
+                        }
+                    }
+
+                    panic!("0.. apparently has zero elements to iterate on??");
+                }
+                PacketValue::Integer(ri) => {
+                    let temp_rl = PacketValue::List(vec![Rc::new(PacketValue::Integer(*ri))]);
+                    self.cmp(&temp_rl)
+                }
+            },
+            PacketValue::Integer(li) => match right {
+                PacketValue::List(_) => {
+                    let temp_ll = PacketValue::List(vec![Rc::new(PacketValue::Integer(*li))]);
+                    temp_ll.cmp(right)
+                }
+                PacketValue::Integer(ri) => li.cmp(ri),
+            },
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Ord, PartialOrd)]
+struct Packet {
+    value: PacketValue,
+    divider: bool,
+}
+
+fn parse(line: Line) -> Result<PacketValue, ParseError> {
+    let s = line.text;
+    let mut stack = Vec::new();
+    let mut cur_list: Vec<Rc<PacketValue>> = Vec::new();
+
+    let mut i = 0;
+    while i < s.len() {
+        let c = line.char_at(i, "a packet value")?;
+
+        if c == '[' {
+            stack.push(Rc::new(RefCell::new(cur_list)));
+            cur_list = Vec::new();
+        } else if c.is_ascii_digit() {
+            let mut value = 0;
+            'int_parse: loop {
+                let vc = s.chars().nth(i).unwrap();
+                let d = vc.to_digit(10).unwrap();
+                value = value * 10 + d;
+
+                i += 1;
+                let nc = line.char_at(i, "\",\" or \"]\"")?;
+                if nc == ',' || nc == ']' {
+                    cur_list.push(Rc::new(PacketValue::Integer(value)));
+                    i -= 1;
+                    break 'int_parse;
+                } else if !nc.is_ascii_digit() {
+                    return Err(line.error_at_char(i, "a digit, \",\" or \"]\""));
+                }
+            }
+        } else if c == ']' {
+            let top_c = stack
+                .pop()
+                .ok_or_else(|| line.error_at_char(i, "a value or \"[\""))?;
+            let mut top = top_c.borrow_mut();
+            top.push(Rc::new(PacketValue::List(cur_list)));
+            cur_list = top.to_vec();
+        } else if c != ',' {
+            return Err(line.error_at_char(i, "a bracket, comma or digit"));
+        }
+
+        i += 1;
+    }
+
+    if !stack.is_empty() {
+        return Err(line.error_at_end("\"]\""));
+    }
+
+    return match cur_list[..] {
+        [ref packet] => match packet.borrow() {
+            PacketValue::Integer(_) => Err(line.error(s, "a list")),
+            PacketValue::List(v) => Ok(PacketValue::List(v.to_vec())),
+        },
+        _ => Err(line.error(s, "a single list")),
+    };
+}
+
+fn divider(i: u32) -> Packet {
+    Packet {
+        value: PacketValue::List(vec![Rc::new(PacketValue::List(vec![Rc::new(
+            PacketValue::Integer(i),
+        )]))]),
+        divider: true,
+    }
+}
+
+// A random packet, with lists nested at most depth deep.
+fn random_packet(rng: &mut Rng, depth: usize) -> String {
+    let len = rng.range(0..=5);
+    let values: Vec<String> = (0..len)
+        .map(|_| {
+            if depth > 0 && rng.chance(0.3) {
+                random_packet(rng, depth - 1)
+            } else {
+                rng.range(0..=10).to_string()
+            }
+        })
+        .collect();
+    return format!("[{}]", values.join(","));
+}
+
+// The same packet with one of its integers changed, so that comparing the two
+// has to go deep into them.
+fn tweak_packet(rng: &mut Rng, packet: &str) -> String {
+    let bytes = packet.as_bytes();
+    let mut integers = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let start = i;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        if i > start {
+            integers.push((start, i));
+        } else {
+            i += 1;
+        }
+    }
+
+    let Some(&(start, end)) = integers.get(rng.index(integers.len().max(1))) else {
+        return random_packet(rng, 4);
+    };
+    return format!(
+        "{}{}{}",
+        &packet[..start],
+        rng.range(0..=10),
+        &packet[end..]
+    );
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    // All packets in order, with blank lines dropped.
+    type Input = Vec<PacketValue>;
+
+    fn parse(input: &str) -> Result<Vec<PacketValue>, ParseError> {
+        lines(input)
+            .filter(|l| !l.text.is_empty())
+            .map(parse)
+            .collect()
+    }
+
+    fn part_a(packets: &Vec<PacketValue>, _: &Context) -> Answer {
+        let mut sum = 0;
+
+        for (i, pair) in packets.chunks(2).enumerate() {
+            let left = &pair[0];
+            let right = pair.get(1).expect("packet should have a pair");
+
+            if left.cmp(right) != Ordering::Greater {
+                sum += i + 1;
+            }
+        }
+
+        return sum.into();
+    }
+
+    fn part_b(packets: &Vec<PacketValue>, _: &Context) -> Answer {
+        let mut packets: Vec<Packet> = packets
+            .iter()
+            .map(|p| match p {
+                PacketValue::Integer(i) => Packet {
+                    value: PacketValue::Integer(*i),
+                    divider: false,
+                },
+                PacketValue::List(v) => Packet {
+                    value: PacketValue::List(v.to_vec()),
+                    divider: false,
+                },
+            })
+            .collect();
+
+        packets.push(divider(2));
+        packets.push(divider(6));
+
+        packets.sort_unstable();
+
+        let mut index: Option<usize> = None;
+        for (i, p) in packets.iter().enumerate() {
+            if p.divider {
+                match index {
+                    Some(f) => {
+                        return (f * (i + 1)).into();
+                    }
+                    None => {
+                        index = Some(i + 1);
+                    }
+                }
+            }
+        }
+
+        panic!("could not find 2 dividers");
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let pairs: Vec<String> = (0..size)
+            .map(|_| {
+                let left = random_packet(rng, 4);
+                let right = if rng.chance(0.5) {
+                    tweak_packet(rng, &left)
+                } else {
+                    random_packet(rng, 4)
+                };
+                format!("{}\n{}\n", left, right)
+            })
+            .collect();
+        return Some(pairs.join("\n"));
+    }
+
+    fn validate(part: Part, raw: &str, packets: &Vec<PacketValue>) -> Vec<ParseError> {
+        if part == Part::A && !packets.len().is_multiple_of(2) {
+            return vec![end_of_input(raw, "a packet to pair with the last one")];
+        }
+        return Vec::new();
+    }
+}
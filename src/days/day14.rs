@@ -0,0 +1,267 @@
+use std::cmp;
+
+use crate::frames::Frame;
+use crate::grid::Grid;
+use crate::parse::{lines, Line};
+use crate::rng::Rng;
+use crate::scan::template;
+use crate::{Answer, Context, ParseError, Part, Solution};
+
+// Where the sand comes from, as (x, y).
+const SOURCE: (u32, u32) = (500, 0);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Air,
+    Rock,
+    Sand,
+}
+
+// Rock and settled sand, indexed by (y, x - left).
+struct Map {
+    tiles: Grid<Tile>,
+    left: u32,
+    // Lowest y coordinate of rock.
+    bottom: u32,
+}
+
+impl Map {
+    fn filled(&self, p: (u32, u32)) -> bool {
+        self.tiles[(p.1 as usize, (p.0 - self.left) as usize)] != Tile::Air
+    }
+
+    fn fill(&mut self, p: (u32, u32), tile: Tile) {
+        self.tiles[(p.1 as usize, (p.0 - self.left) as usize)] = tile;
+    }
+
+    // Draws the map like the puzzle does, in its own (y, x) coordinates.
+    fn frame(&self) -> Frame {
+        let mut cells = Grid::from_fn(self.tiles.height(), self.tiles.width(), |pos| {
+            match self.tiles[pos] {
+                Tile::Air => b'.',
+                Tile::Rock => b'#',
+                Tile::Sand => b'o',
+            }
+        });
+        if !self.filled(SOURCE) {
+            cells[(SOURCE.1 as usize, (SOURCE.0 - self.left) as usize)] = b'+';
+        }
+        return Frame::new(0, self.left as i64, cells);
+    }
+}
+
+fn parse_point(line: Line, s: &str) -> Result<(u32, u32), ParseError> {
+    let [x, y] = template(line, s, "{},{}")?;
+    return Ok((
+        line.parse(x, "an x coordinate")?,
+        line.parse(y, "a y coordinate")?,
+    ));
+}
+
+// Draws the rock paths onto a new map. The map is wide and deep enough to
+// hold all the sand that can pile up under the source on a floor 2 below the
+// lowest rock.
+fn build_map(paths: &[Vec<(u32, u32)>]) -> Map {
+    let points = || paths.iter().flatten();
+    let bottom = points().map(|p| p.1).max().unwrap_or(0);
+    let spread = bottom + 2;
+    let left = cmp::min(
+        points().map(|p| p.0).min().unwrap_or(SOURCE.0),
+        SOURCE.0.saturating_sub(spread),
+    );
+    let right = cmp::max(
+        points().map(|p| p.0).max().unwrap_or(SOURCE.0),
+        SOURCE.0 + spread,
+    );
+
+    let mut map = Map {
+        tiles: Grid::new(spread as usize + 1, (right - left) as usize + 1, Tile::Air),
+        left: left,
+        bottom: bottom,
+    };
+
+    for path in paths {
+        let mut points = path.iter();
+        let mut last_point = *points.next().expect("path should have first point");
+
+        for &point in points {
+            while last_point != point {
+                map.fill(last_point, Tile::Rock);
+                if last_point.0 == point.0 {
+                    if last_point.1 < point.1 {
+                        last_point.1 += 1;
+                    } else {
+                        last_point.1 -= 1;
+                    }
+                } else if last_point.1 == point.1 {
+                    if last_point.0 < point.0 {
+                        last_point.0 += 1;
+                    } else {
+                        last_point.0 -= 1;
+                    }
+                } else {
+                    panic!("path points should be axis-aligned");
+                }
+            }
+
+            map.fill(last_point, Tile::Rock);
+        }
+    }
+
+    return map;
+}
+
+// Drops one unit of sand from the source and returns where it comes to rest,
+// or None if it falls past the lowest rock. With a floor, sand rests on it
+// instead, 1 below the lowest rock.
+fn drop_sand(map: &Map, floor: bool) -> Option<(u32, u32)> {
+    let mut sand = SOURCE;
+
+    'sand_sim: loop {
+        if sand.1 == map.bottom + 1 {
+            // Sand is below all the rock.
+            return if floor { Some(sand) } else { None };
+        }
+
+        for next in [
+            (sand.0, sand.1 + 1),
+            (sand.0 - 1, sand.1 + 1),
+            (sand.0 + 1, sand.1 + 1),
+        ] {
+            if !map.filled(next) {
+                sand = next;
+                continue 'sand_sim;
+            }
+        }
+
+        // Sand is at rest because it cannot move further.
+        return Some(sand);
+    }
+}
+
+// Whether sand ends up falling past the rock in part A. Part A doesn't expect
+// it to pile up all the way to the source instead.
+fn sand_escapes(paths: &[Vec<(u32, u32)>]) -> bool {
+    let mut map = build_map(paths);
+    while let Some(sand) = drop_sand(&map, false) {
+        if sand == SOURCE {
+            return false;
+        }
+        map.fill(sand, Tile::Sand);
+    }
+    return true;
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    // Rock paths, each as a list of points.
+    type Input = Vec<Vec<(u32, u32)>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<(u32, u32)>>, ParseError> {
+        lines(input)
+            .map(|line| {
+                line.text
+                    .split("->")
+                    .map(|s| parse_point(line, s))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part_a(paths: &Vec<Vec<(u32, u32)>>, ctx: &Context) -> Answer {
+        let mut map = build_map(paths);
+        ctx.frame(|| map.frame());
+
+        let mut sands = 0;
+        while let Some(sand) = drop_sand(&map, false) {
+            map.fill(sand, Tile::Sand);
+            ctx.frame(|| map.frame());
+            sands += 1;
+        }
+
+        return sands.into();
+    }
+
+    fn part_b(paths: &Vec<Vec<(u32, u32)>>, ctx: &Context) -> Answer {
+        let mut map = build_map(paths);
+        ctx.frame(|| map.frame());
+
+        let mut sands = 0;
+        while !map.filled(SOURCE) {
+            let sand = drop_sand(&map, true).expect("sand should rest on the floor");
+            map.fill(sand, Tile::Sand);
+            ctx.frame(|| map.frame());
+            sands += 1;
+        }
+
+        return sands.into();
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        // Rock paths of straight lines, around and below the source so that
+        // the sand lands on them.
+        let depth = (size as i64).max(10);
+        let paths = loop {
+            let paths: Vec<Vec<(u32, u32)>> = (0..size)
+                .map(|_| {
+                    let (mut x, mut y) = (rng.range(450..=550), rng.range(2..=depth));
+                    let mut path = vec![(x as u32, y as u32)];
+                    for i in 0..rng.range(1..=5) {
+                        let step = rng.range(1..=8) * if rng.chance(0.5) { 1 } else { -1 };
+                        if i % 2 == 0 {
+                            x += step;
+                        } else {
+                            y = (y + step).clamp(2, depth);
+                        }
+                        if path.last() != Some(&(x as u32, y as u32)) {
+                            path.push((x as u32, y as u32));
+                        }
+                    }
+                    path
+                })
+                .collect();
+            if sand_escapes(&paths) {
+                break paths;
+            }
+        };
+
+        let paths = paths
+            .iter()
+            .map(|path| {
+                let points: Vec<String> =
+                    path.iter().map(|(x, y)| format!("{},{}", x, y)).collect();
+                points.join(" -> ") + "\n"
+            })
+            .collect();
+        return Some(paths);
+    }
+
+    fn validate(_: Part, raw: &str, paths: &Vec<Vec<(u32, u32)>>) -> Vec<ParseError> {
+        let mut problems = Vec::new();
+        for (line, path) in lines(raw).zip(paths) {
+            let tokens: Vec<&str> = line.text.split(" -> ").collect();
+
+            // Each point is checked along with the stretch of rock leading up
+            // to it, and the first on its own.
+            for (k, &to) in path.iter().enumerate() {
+                let from = path[k.saturating_sub(1)];
+                let xs = cmp::min(from.0, to.0)..=cmp::max(from.0, to.0);
+                let ys = cmp::min(from.1, to.1)..=cmp::max(from.1, to.1);
+                if from.0 != to.0 && from.1 != to.1 {
+                    problems.push(line.error(tokens[k], "a point in line with the one before"));
+                } else if xs.contains(&SOURCE.0)
+                    && ys.contains(&SOURCE.1)
+                    && (k == 0 || from != SOURCE)
+                {
+                    problems.push(line.error(
+                        tokens[k],
+                        &format!("rock clear of the sand source at {},{}", SOURCE.0, SOURCE.1),
+                    ));
+                }
+            }
+        }
+
+        return problems;
+    }
+}
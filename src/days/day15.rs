@@ -0,0 +1,178 @@
+use std::collections::HashSet;
+use std::vec::Vec;
+
+use crate::interval::{Interval, RangeSet};
+use crate::parse::lines;
+use crate::point::Point2;
+use crate::rng::Rng;
+use crate::scan::ints;
+use crate::{Answer, Context, Param, ParseError, Solution};
+
+// Largest x and y of the real input's search area in part B.
+const COORD_MAX: i64 = 4000000;
+
+struct Sensor {
+    location: Point2,
+    radius: u64,
+}
+
+pub struct Input {
+    sensors: Vec<Sensor>,
+    beacons: HashSet<Point2>,
+}
+
+// Locations in a row that are within radius of any sensor.
+fn row_coverage(sensors: &[Sensor], row: i64) -> RangeSet {
+    sensors
+        .iter()
+        .filter_map(|sensor| {
+            let axis_dist = sensor.location.y.abs_diff(row);
+            if axis_dist > sensor.radius {
+                return None;
+            }
+
+            let remaining_dist = (sensor.radius - axis_dist) as i64;
+            Some(Interval::new(
+                sensor.location.x - remaining_dist,
+                sensor.location.x + remaining_dist,
+            ))
+        })
+        .collect()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Input;
+
+    // The sample uses a smaller row and search area than the real input,
+    // which aoc.toml sets.
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "target",
+            default: 2000000,
+            min: i64::MIN,
+            max: i64::MAX,
+            description: "Row to count covered positions in for part A",
+        },
+        Param {
+            name: "coord_max",
+            default: COORD_MAX,
+            min: 0,
+            max: 1000000000,
+            description: "Largest x and y of the area searched in part B",
+        },
+        Param {
+            name: "multiplier",
+            default: 4000000,
+            min: 0,
+            max: 1000000000,
+            description: "Multiplier for x in the part B tuning frequency",
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        // Read in input and store locations + radii of sensors, plus beacons.
+        let mut sensors = Vec::new();
+        let mut beacons = HashSet::new();
+
+        for line in lines(input) {
+            let coords: Vec<i64> = ints(line, line.text, "a coordinate")?;
+            let [sensor_x, sensor_y, beacon_x, beacon_y] = coords[..] else {
+                return Err(line.error(line.text, "a sensor and beacon position"));
+            };
+            let sensor = Point2::new(sensor_x, sensor_y);
+            let beacon = Point2::new(beacon_x, beacon_y);
+
+            sensors.push(Sensor {
+                location: sensor,
+                radius: sensor.manhattan(beacon),
+            });
+
+            beacons.insert(beacon);
+        }
+
+        return Ok(Input {
+            sensors: sensors,
+            beacons: beacons,
+        });
+    }
+
+    fn part_a(input: &Input, ctx: &Context) -> Answer {
+        let target = ctx.param("target");
+
+        // Locations at target coordinate that are within radius of a sensor,
+        // apart from those that are actually occupied by beacons.
+        let covered = row_coverage(&input.sensors, target);
+        let beacons: RangeSet = input
+            .beacons
+            .iter()
+            .filter(|beacon| beacon.y == target)
+            .map(|beacon| Interval::point(beacon.x))
+            .collect();
+
+        return covered.difference(&beacons).len().into();
+    }
+
+    fn part_b(input: &Input, ctx: &Context) -> Answer {
+        let coord_max = ctx.param("coord_max");
+        let bounds = Interval::new(0, coord_max);
+
+        // Find the point which is not covered by the sensors.
+        for y in 0..=coord_max {
+            if let Some(gap) = row_coverage(&input.sensors, y).gaps(bounds).first() {
+                let multiplier = ctx.param("multiplier") as u64;
+                return (multiplier * gap.start as u64 + y as u64).into();
+            }
+        }
+
+        panic!("should have found an uncovered point");
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        // Part B's search area is as big as the real one, with exactly one
+        // point in it that no sensor covers. The size is the number of
+        // sensors.
+        let coord_max = COORD_MAX;
+        let hidden = Point2::new(rng.range(0..=coord_max), rng.range(0..=coord_max));
+
+        // A sensor far off in each diagonal direction, reaching just short of
+        // the hidden point, covers the rest of that quarter of the area.
+        let mut sensors = Vec::new();
+        for (sx, sy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+            let dx = rng.range(coord_max + 1..=2 * coord_max + 1);
+            let dy = rng.range(coord_max + 1..=2 * coord_max + 1);
+            let location = Point2::new(hidden.x + sx * dx, hidden.y + sy * dy);
+            sensors.push((location, location.manhattan(hidden) - 1));
+        }
+        // More sensors anywhere, none of them covering the hidden point.
+        while sensors.len() < size.max(4) {
+            let margin = coord_max / 5;
+            let location = Point2::new(
+                rng.range(-margin..=coord_max + margin),
+                rng.range(-margin..=coord_max + margin),
+            );
+            let dist = location.manhattan(hidden) as i64;
+            if dist > 1 {
+                let radius = rng.range(dist / 2..=dist - 1) as u64;
+                sensors.push((location, radius));
+            }
+        }
+        rng.shuffle(&mut sensors);
+
+        let mut input = String::new();
+        for (location, radius) in sensors {
+            let radius = radius as i64;
+            let dx = rng.range(-radius..=radius);
+            let dy = (radius - dx.abs()) * if rng.chance(0.5) { 1 } else { -1 };
+            input += &format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                location.x,
+                location.y,
+                location.x + dx,
+                location.y + dy
+            );
+        }
+        return Some(input);
+    }
+}
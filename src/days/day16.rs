@@ -0,0 +1,733 @@
+use std::cmp;
+use std::collections::{HashMap, HashSet};
+use std::vec::Vec;
+
+use crate::parse::{end_of_input, lines};
+use crate::rng::Rng;
+use crate::scan::template;
+use crate::search::{bfs, Graph};
+use crate::stats::SearchStats;
+use crate::{Answer, Context, Param, ParseError, Part, Solution};
+
+struct Valve {
+    flow: u32,
+    tunnels: Vec<String>,
+}
+
+pub struct Input {
+    valves: HashMap<String, Valve>,
+    nonzero_valves: HashSet<String>,
+}
+
+struct SingleSearchState {
+    time: u8,
+    valve: String,
+    pressure: u32,
+    flow_rate: u32,
+    opened: HashSet<String>,
+}
+
+struct PairSearchState {
+    time: u8,
+    searcher: [SearcherState; 2],
+    pressure: u32,
+    flow_rate: u32,
+    to_open: HashSet<String>,
+}
+
+struct SearcherState {
+    valve: String,
+    // 0 means searcher is at valve, else searcher will open valve in "distance"
+    // minutes.
+    distance: u8,
+}
+
+// The tunnels as a graph of valve names.
+struct Tunnels<'a>(&'a HashMap<String, Valve>);
+
+impl<'a> Graph for Tunnels<'a> {
+    type Node = &'a str;
+
+    fn neighbours(&self, valve: &&'a str) -> Vec<(&'a str, u64)> {
+        self.0
+            .get(*valve)
+            .expect("tunnels should lead to valves in input")
+            .tunnels
+            .iter()
+            .map(|t| (t.as_str(), 1))
+            .collect()
+    }
+}
+
+// Finds the time taken to go from AA or any non-zero valve to each non-zero
+// valve and open it, keyed by (from, to).
+fn build_dist_map(input: &Input) -> HashMap<(String, String), u8> {
+    let tunnels = Tunnels(&input.valves);
+    let mut dists = HashMap::new();
+
+    for from in input
+        .nonzero_valves
+        .iter()
+        .map(String::as_str)
+        .chain(["AA"])
+    {
+        let search = bfs(&tunnels, [from], |_| false);
+        for to in &input.nonzero_valves {
+            if let Some(dist) = search.cost(&to.as_str()) {
+                dists.insert((from.to_string(), to.clone()), dist as u8 + 1);
+            }
+        }
+    }
+
+    return dists;
+}
+
+// Most pressure one searcher can release from each set of valves by opening
+// exactly those, trying every order of opening them. Sets are bitmasks over
+// the valves in the given order.
+fn pressure_by_set(
+    input: &Input,
+    valves: &[&String],
+    dists: &HashMap<(String, String), u8>,
+    minutes: u32,
+) -> HashMap<u32, u32> {
+    fn search(
+        input: &Input,
+        valves: &[&String],
+        dists: &HashMap<(String, String), u8>,
+        (from, time_left, opened, pressure): (&str, u32, u32, u32),
+        best: &mut HashMap<u32, u32>,
+    ) {
+        let entry = best.entry(opened).or_insert(0);
+        *entry = cmp::max(*entry, pressure);
+
+        for (i, &valve) in valves.iter().enumerate() {
+            if opened & (1 << i) != 0 {
+                continue;
+            }
+            let Some(&dist) = dists.get(&(from.to_string(), valve.clone())) else {
+                continue;
+            };
+            let dist = dist as u32;
+            if dist < time_left {
+                let released = input.valves[valve].flow * (time_left - dist);
+                search(
+                    input,
+                    valves,
+                    dists,
+                    (
+                        valve,
+                        time_left - dist,
+                        opened | (1 << i),
+                        pressure + released,
+                    ),
+                    best,
+                );
+            }
+        }
+    }
+
+    let mut best = HashMap::new();
+    search(input, valves, dists, ("AA", minutes, 0, 0), &mut best);
+    return best;
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Input;
+
+    const PARAMS: &'static [Param] = &[Param {
+        name: "minutes",
+        default: 26,
+        min: 1,
+        max: 100,
+        description: "Minutes available in part B",
+    }];
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        let mut valves = HashMap::new();
+        let mut nonzero_valves = HashSet::new();
+        // Where each tunnel's name is, to check once every valve is known.
+        let mut tunnel_names = Vec::new();
+
+        for line in lines(input) {
+            // The tunnel part is plural if there's more than one tunnel.
+            let [name, flow, _, _, _, tunnels] = template(
+                line,
+                line.text,
+                "Valve {} has flow rate={}; tunnel{} lead{} to valve{} {}",
+            )?;
+            let name = String::from(name);
+            let flow: u32 = line.parse(flow, "a flow rate")?;
+            let tunnels: Vec<&str> = tunnels.split(',').map(str::trim).collect();
+            tunnel_names.extend(tunnels.iter().map(|&t| (line, t)));
+            let tunnels: Vec<String> = tunnels.into_iter().map(String::from).collect();
+
+            valves.insert(
+                name.clone(),
+                Valve {
+                    flow: flow,
+                    tunnels: tunnels,
+                },
+            );
+            if flow > 0 {
+                nonzero_valves.insert(name);
+            }
+        }
+
+        for (line, tunnel) in tunnel_names {
+            if !valves.contains_key(tunnel) {
+                return Err(line.error(tunnel, "the name of a valve"));
+            }
+        }
+        if !valves.contains_key("AA") {
+            return Err(end_of_input(input, "a valve named AA"));
+        }
+
+        return Ok(Input {
+            valves: valves,
+            nonzero_valves: nonzero_valves,
+        });
+    }
+
+    fn part_a(input: &Input, ctx: &Context) -> Answer {
+        let valves = &input.valves;
+        let nonzero_valves = &input.nonzero_valves;
+
+        let mut max = 0;
+        let mut search = vec![SingleSearchState {
+            time: 1,
+            valve: String::from("AA"),
+            pressure: 0,
+            flow_rate: 0,
+            opened: HashSet::new(),
+        }];
+        let dists = build_dist_map(input);
+        let mut stats = SearchStats::default();
+
+        while !search.is_empty() {
+            let state = &search.pop().unwrap();
+            stats.expand();
+
+            // Try going to all unopened, non-zero valves.
+            for target_valve in nonzero_valves.difference(&state.opened) {
+                let key = (state.valve.clone(), target_valve.clone());
+                let Some(&dist) = dists.get(&key) else {
+                    continue;
+                };
+
+                let new_time = state.time + dist;
+                if new_time <= 30 {
+                    let mut new_opened = state.opened.clone();
+                    new_opened.insert(target_valve.clone());
+
+                    search.push(SingleSearchState {
+                        time: new_time,
+                        valve: target_valve.clone(),
+                        pressure: state.pressure + state.flow_rate * dist as u32,
+                        flow_rate: state.flow_rate + valves.get(target_valve).unwrap().flow,
+                        opened: new_opened,
+                    });
+                } else {
+                    stats.prune("out of time", 1);
+                }
+            }
+            stats.frontier(search.len());
+
+            // Account for idle state from this position.
+            let idle_min = 31 - state.time;
+            let pressure = state.pressure + state.flow_rate * idle_min as u32;
+            if pressure > max {
+                max = pressure;
+            }
+        }
+
+        ctx.report_search(&stats);
+        return max.into();
+    }
+
+    fn part_b(input: &Input, ctx: &Context) -> Answer {
+        let minutes = ctx.param("minutes") as u8;
+        let valves = &input.valves;
+        let nonzero_valves = &input.nonzero_valves;
+
+        let mut max = 0;
+        let mut search = vec![PairSearchState {
+            time: 1,
+            searcher: [
+                SearcherState {
+                    valve: String::from("AA"),
+                    distance: 0,
+                },
+                SearcherState {
+                    valve: String::from("AA"),
+                    distance: 0,
+                },
+            ],
+            pressure: 0,
+            flow_rate: 0,
+            to_open: nonzero_valves.clone(),
+        }];
+        // dists contains the time taken to go from one valve to another.
+        let dists = build_dist_map(input);
+        let mut stats = SearchStats::default();
+
+        while !search.is_empty() {
+            let state = &search.pop().unwrap();
+            stats.expand();
+
+            if state.searcher[0].distance == 0 && state.searcher[1].distance == 0 {
+                // Both searchers need new targets.
+                let time_left = minutes - 1 - state.time;
+                let from_0 = &state.searcher[0].valve;
+                let from_1 = &state.searcher[1].valve;
+
+                if from_0 == from_1 {
+                    // Since both searchers are currently at the same valve,
+                    // symmetric assignments of 2 tunnels are equivalent. Send
+                    // searcher 0 to the closer tunnel of the 2 to reduce search
+                    // state space since both combinations would yield the same
+                    // result.
+                    let mut target_list: Vec<&String> = state
+                        .to_open
+                        .iter()
+                        .filter(|v| {
+                            dists
+                                .get(&(from_1.clone(), v.to_string()))
+                                .is_some_and(|&d| d < time_left)
+                        })
+                        .collect();
+                    target_list.sort_unstable_by_key(|v| {
+                        dists.get(&(from_1.clone(), v.to_string())).unwrap()
+                    });
+                    stats.prune(
+                        "out of time",
+                        (state.to_open.len() - target_list.len()) as u64,
+                    );
+                    let n = target_list.len() as u64;
+                    stats.prune("same valve", n * n.saturating_sub(1) / 2);
+
+                    if target_list.len() == 1 {
+                        // Only one target left, send searcher to last target.
+                        let target = target_list[0];
+                        let mut new_to_open = state.to_open.clone();
+                        new_to_open.remove(target);
+
+                        search.push(PairSearchState {
+                            time: state.time,
+                            searcher: [
+                                SearcherState {
+                                    valve: target.clone(),
+                                    distance: *dists
+                                        .get(&(from_1.clone(), target.clone()))
+                                        .unwrap(),
+                                },
+                                SearcherState {
+                                    valve: from_1.clone(),
+                                    distance: 0,
+                                },
+                            ],
+                            pressure: state.pressure,
+                            flow_rate: state.flow_rate,
+                            to_open: new_to_open,
+                        });
+                    } else {
+                        for j in 1..target_list.len() {
+                            let valve_j = target_list[j];
+                            for i in 0..j {
+                                let mut new_to_open = state.to_open.clone();
+                                let valve_i = target_list[i];
+                                new_to_open.remove(valve_i);
+                                new_to_open.remove(valve_j);
+
+                                search.push(PairSearchState {
+                                    time: state.time,
+                                    searcher: [
+                                        SearcherState {
+                                            valve: valve_i.clone(),
+                                            distance: *dists
+                                                .get(&(from_0.clone(), valve_i.clone()))
+                                                .unwrap(),
+                                        },
+                                        SearcherState {
+                                            valve: valve_j.clone(),
+                                            distance: *dists
+                                                .get(&(from_0.clone(), valve_j.clone()))
+                                                .unwrap(),
+                                        },
+                                    ],
+                                    pressure: state.pressure,
+                                    flow_rate: state.flow_rate,
+                                    to_open: new_to_open,
+                                });
+                            }
+                        }
+                    }
+                } else {
+                    let target_list_0: Vec<&String> = state
+                        .to_open
+                        .iter()
+                        .filter(|v| {
+                            dists
+                                .get(&(from_0.clone(), v.to_string()))
+                                .is_some_and(|&d| d < time_left)
+                        })
+                        .collect();
+                    let target_list_1: Vec<&String> = state
+                        .to_open
+                        .iter()
+                        .filter(|v| {
+                            dists
+                                .get(&(from_1.clone(), v.to_string()))
+                                .is_some_and(|&d| d < time_left)
+                        })
+                        .collect();
+                    for target_list in [&target_list_0, &target_list_1] {
+                        stats.prune(
+                            "out of time",
+                            (state.to_open.len() - target_list.len()) as u64,
+                        );
+                    }
+
+                    if target_list_0.is_empty() && target_list_1.is_empty() {
+                        // Neither searcher has candidate targets, so just end.
+                    } else if !target_list_0.is_empty() && !target_list_1.is_empty() {
+                        // Both searchers have candidate targets, so add every
+                        // combination of targets to search.
+                        for t0 in &target_list_0 {
+                            for t1 in &target_list_1 {
+                                if t0 == t1 {
+                                    // Don't move both searchers to the same target,
+                                    // as that would cause a double count. Try
+                                    // either searcher going to the target (the next
+                                    // iteration will pick a target for the other
+                                    // searcher).
+                                    let mut new_to_open = state.to_open.clone();
+                                    new_to_open.remove(*t0);
+
+                                    search.push(PairSearchState {
+                                        time: state.time,
+                                        searcher: [
+                                            SearcherState {
+                                                valve: t0.to_string(),
+                                                distance: *dists
+                                                    .get(&(from_0.clone(), t0.to_string()))
+                                                    .unwrap(),
+                                            },
+                                            SearcherState {
+                                                valve: state.searcher[1].valve.clone(),
+                                                distance: 0,
+                                            },
+                                        ],
+                                        pressure: state.pressure,
+                                        flow_rate: state.flow_rate,
+                                        to_open: new_to_open.clone(),
+                                    });
+                                    search.push(PairSearchState {
+                                        time: state.time,
+                                        searcher: [
+                                            SearcherState {
+                                                valve: state.searcher[0].valve.clone(),
+                                                distance: 0,
+                                            },
+                                            SearcherState {
+                                                valve: t0.to_string(),
+                                                distance: *dists
+                                                    .get(&(from_1.clone(), t0.to_string()))
+                                                    .unwrap(),
+                                            },
+                                        ],
+                                        pressure: state.pressure,
+                                        flow_rate: state.flow_rate,
+                                        to_open: new_to_open.clone(),
+                                    });
+                                } else {
+                                    let mut new_to_open = state.to_open.clone();
+                                    new_to_open.remove(*t0);
+                                    new_to_open.remove(*t1);
+
+                                    search.push(PairSearchState {
+                                        time: state.time,
+                                        searcher: [
+                                            SearcherState {
+                                                valve: t0.to_string(),
+                                                distance: *dists
+                                                    .get(&(from_0.clone(), t0.to_string()))
+                                                    .unwrap(),
+                                            },
+                                            SearcherState {
+                                                valve: t1.to_string(),
+                                                distance: *dists
+                                                    .get(&(from_1.clone(), t1.to_string()))
+                                                    .unwrap(),
+                                            },
+                                        ],
+                                        pressure: state.pressure,
+                                        flow_rate: state.flow_rate,
+                                        to_open: new_to_open,
+                                    });
+                                }
+                            }
+                        }
+                    } else if target_list_0.is_empty() {
+                        // Searcher 0 has no candidate target, send searcher 1
+                        // to its targets.
+                        for t1 in target_list_1 {
+                            let mut new_to_open = state.to_open.clone();
+                            new_to_open.remove(t1);
+
+                            search.push(PairSearchState {
+                                time: state.time,
+                                searcher: [
+                                    SearcherState {
+                                        valve: from_0.clone(),
+                                        distance: 0,
+                                    },
+                                    SearcherState {
+                                        valve: t1.clone(),
+                                        distance: *dists
+                                            .get(&(from_1.clone(), t1.clone()))
+                                            .unwrap(),
+                                    },
+                                ],
+                                pressure: state.pressure,
+                                flow_rate: state.flow_rate,
+                                to_open: new_to_open,
+                            });
+                        }
+                    } else if target_list_1.is_empty() {
+                        // Searcher 1 has no candidate target, send searcher 0
+                        // to its targets.
+                        for t0 in target_list_0 {
+                            let mut new_to_open = state.to_open.clone();
+                            new_to_open.remove(t0);
+
+                            search.push(PairSearchState {
+                                time: state.time,
+                                searcher: [
+                                    SearcherState {
+                                        valve: t0.clone(),
+                                        distance: *dists
+                                            .get(&(from_0.clone(), t0.clone()))
+                                            .unwrap(),
+                                    },
+                                    SearcherState {
+                                        valve: from_1.clone(),
+                                        distance: 0,
+                                    },
+                                ],
+                                pressure: state.pressure,
+                                flow_rate: state.flow_rate,
+                                to_open: new_to_open,
+                            });
+                        }
+                    }
+                }
+            } else if state.searcher[0].distance > 0 && state.searcher[1].distance > 0 {
+                // Both searchers are going to a target. Advance time until one or
+                // both reach its target.
+                let time_adv = cmp::min(state.searcher[0].distance, state.searcher[1].distance);
+                let new_time = state.time + time_adv;
+                if new_time < minutes {
+                    let mut new_flow_rate = state.flow_rate;
+                    for searcher in &state.searcher {
+                        if searcher.distance == time_adv {
+                            new_flow_rate += valves.get(&searcher.valve).unwrap().flow;
+                        }
+                    }
+
+                    search.push(PairSearchState {
+                        time: new_time,
+                        searcher: [
+                            SearcherState {
+                                valve: state.searcher[0].valve.clone(),
+                                distance: state.searcher[0].distance - time_adv,
+                            },
+                            SearcherState {
+                                valve: state.searcher[1].valve.clone(),
+                                distance: state.searcher[1].distance - time_adv,
+                            },
+                        ],
+                        pressure: state.pressure + time_adv as u32 * state.flow_rate,
+                        flow_rate: new_flow_rate,
+                        to_open: state.to_open.clone(),
+                    });
+                } else {
+                    stats.prune("out of time", 1);
+                }
+            } else {
+                // One searcher needs a new target.
+                let new_target_searcher_index = if state.searcher[0].distance == 0 {
+                    0
+                } else {
+                    1
+                };
+                let new_target_searcher = &state.searcher[new_target_searcher_index];
+                let other_searcher = &state.searcher[1 - new_target_searcher_index];
+
+                let time_left = minutes - 1 - state.time;
+                let from = &new_target_searcher.valve;
+
+                let mut target_list: Vec<&String> = state
+                    .to_open
+                    .iter()
+                    .filter(|v| {
+                        dists
+                            .get(&(from.clone(), v.to_string()))
+                            .is_some_and(|&d| d < time_left)
+                    })
+                    .collect();
+                target_list
+                    .sort_unstable_by_key(|v| dists.get(&(from.clone(), v.to_string())).unwrap());
+                stats.prune(
+                    "out of time",
+                    (state.to_open.len() - target_list.len()) as u64,
+                );
+
+                if target_list.is_empty() {
+                    // No more viable targets to go to, just advance time until the
+                    // other searcher reaches its target.
+                    let time_adv = other_searcher.distance;
+                    let new_time = state.time + time_adv;
+                    if new_time <= minutes {
+                        let new_flow_rate =
+                            state.flow_rate + valves.get(&other_searcher.valve).unwrap().flow;
+                        search.push(PairSearchState {
+                            time: new_time,
+                            searcher: [
+                                SearcherState {
+                                    valve: state.searcher[0].valve.clone(),
+                                    distance: 0,
+                                },
+                                SearcherState {
+                                    valve: state.searcher[1].valve.clone(),
+                                    distance: 0,
+                                },
+                            ],
+                            pressure: state.pressure + time_adv as u32 * state.flow_rate,
+                            flow_rate: new_flow_rate,
+                            to_open: state.to_open.clone(),
+                        });
+                    }
+                } else {
+                    for target in target_list {
+                        let mut new_to_open = state.to_open.clone();
+                        new_to_open.remove(target);
+
+                        search.push(PairSearchState {
+                            time: state.time,
+                            searcher: [
+                                SearcherState {
+                                    valve: target.clone(),
+                                    distance: *dists.get(&(from.clone(), target.clone())).unwrap(),
+                                },
+                                SearcherState {
+                                    valve: other_searcher.valve.clone(),
+                                    distance: other_searcher.distance,
+                                },
+                            ],
+                            pressure: state.pressure,
+                            flow_rate: state.flow_rate,
+                            to_open: new_to_open,
+                        });
+                    }
+                }
+            }
+
+            stats.frontier(search.len());
+
+            // Account for idle state from this position.
+            let idle_min = minutes + 1 - state.time;
+            let pressure = state.pressure + state.flow_rate * idle_min as u32;
+            if pressure > max {
+                max = pressure;
+            }
+        }
+
+        ctx.report_search(&stats);
+        return max.into();
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        // Valve names are 2 capital letters, and the start is AA.
+        let num_valves = size.clamp(2, 26 * 26);
+        let mut names = vec![String::from("AA")];
+        let mut taken = HashSet::from([String::from("AA")]);
+        while names.len() < num_valves {
+            let name: String = (0..2)
+                .map(|_| (b'A' + rng.below(26) as u8) as char)
+                .collect();
+            if taken.insert(name.clone()) {
+                names.push(name);
+            }
+        }
+
+        // Every valve can be reached, and a few tunnels make loops.
+        let mut tunnels = vec![HashSet::new(); num_valves];
+        for i in 1..num_valves {
+            let j = rng.index(i);
+            tunnels[i].insert(j);
+            tunnels[j].insert(i);
+        }
+        for _ in 0..num_valves / 4 {
+            let (i, j) = (rng.index(num_valves), rng.index(num_valves));
+            if i != j {
+                tunnels[i].insert(j);
+                tunnels[j].insert(i);
+            }
+        }
+
+        // Like the real inputs, only some valves have any flow, and AA isn't
+        // one of them. There are few enough for part B to try every split.
+        let mut flows = vec![0; num_valves];
+        let mut order: Vec<usize> = (1..num_valves).collect();
+        rng.shuffle(&mut order);
+        for &i in order.iter().take((num_valves / 3).clamp(1, 15)) {
+            flows[i] = rng.range(2..=25);
+        }
+
+        let mut scan: Vec<String> = (0..num_valves)
+            .map(|i| {
+                let mut to: Vec<&str> = tunnels[i].iter().map(|&j| names[j].as_str()).collect();
+                rng.shuffle(&mut to);
+                let plural = if to.len() == 1 { "" } else { "s" };
+                format!(
+                    "Valve {} has flow rate={}; tunnel{} lead{} to valve{} {}\n",
+                    names[i],
+                    flows[i],
+                    plural,
+                    if to.len() == 1 { "s" } else { "" },
+                    plural,
+                    to.join(", ")
+                )
+            })
+            .collect();
+        rng.shuffle(&mut scan);
+        return Some(scan.concat());
+    }
+
+    fn reference(part: Part, input: &Input, ctx: &Context) -> Option<Answer> {
+        if part == Part::A {
+            return None;
+        }
+
+        // You and the elephant work independently, so the best either can do
+        // only depends on which valves each of you opens. Try every pair of
+        // sets that don't share a valve.
+        let valves: Vec<&String> = input.nonzero_valves.iter().collect();
+        let dists = build_dist_map(input);
+        let best = pressure_by_set(input, &valves, &dists, ctx.param("minutes") as u32);
+
+        let mut max = 0;
+        for (&yours, &your_pressure) in &best {
+            for (&elephants, &elephant_pressure) in &best {
+                if yours & elephants == 0 {
+                    max = cmp::max(max, your_pressure + elephant_pressure);
+                }
+            }
+        }
+
+        return Some(max.into());
+    }
+}
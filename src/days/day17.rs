@@ -0,0 +1,242 @@
+use std::vec::Vec;
+
+use crate::cycle;
+use crate::frames::Frame;
+use crate::grid::Grid;
+use crate::parse::{end_of_input, lines};
+use crate::point::Direction;
+use crate::rng::Rng;
+use crate::stats::SearchStats;
+use crate::{Answer, Context, Param, ParseError, Solution};
+
+struct Shape {
+    // width and height are used for positioning calculations/checks. Make sure
+    // they correspond with the coordinates in cells.
+    width: u8,
+    height: u8,
+    // cells are which cells the shape occupy, in (row, column) format, where
+    // (0, 0) represents the bottom-left corner of the shape and increasing
+    // coordinates move towards the top-right.
+    cells: Vec<(i8, i8)>,
+}
+
+// Width of the chamber.
+const WIDTH: usize = 7;
+
+// Returns whether the cell in the chamber is occupied/blocked. Row 0 of the
+// chamber is the bottom.
+fn check_cell(chamber: &Grid<bool>, cell: (i64, i8)) -> bool {
+    if cell.0 < 0 {
+        return true;
+    }
+    if cell.1 < 0 {
+        return true;
+    }
+
+    match chamber.get((cell.0 as usize, cell.1 as usize)) {
+        Some(&filled) => filled,
+        // Past the right wall, or above the top of the rocks.
+        None => cell.1 as usize >= WIDTH,
+    }
+}
+
+// Returns whether the cells in the specified shape are occupied/blocked.
+fn check_shape(chamber: &Grid<bool>, shape: &Shape, pos: (i64, i8)) -> bool {
+    if pos.0 < 0 {
+        return true;
+    }
+    if pos.1 < 0 || pos.1 + shape.width as i8 > WIDTH as i8 {
+        return true;
+    }
+
+    return shape
+        .cells
+        .iter()
+        .any(|c| check_cell(chamber, (pos.0 + c.0 as i64, pos.1 + c.1)));
+}
+
+// Fills in the shape onto the chamber at the specified position.
+fn fill_shape(chamber: &mut Grid<bool>, shape: &Shape, pos: (i64, i8)) {
+    let max_row = pos.0 as usize + shape.height as usize - 1;
+
+    while max_row >= chamber.height() {
+        chamber.push_row([false; WIDTH]);
+    }
+
+    for c in &shape.cells {
+        chamber[((pos.0 + c.0 as i64) as usize, (pos.1 + c.1) as usize)] = true;
+    }
+}
+
+// Drops the shape into the chamber, pushing it with the jet pattern starting at
+// pattern_index, until it comes to rest.
+fn drop_shape(
+    chamber: &mut Grid<bool>,
+    shape: &Shape,
+    pattern: &[Direction],
+    pattern_index: &mut usize,
+) {
+    let mut pos: (i64, i8) = (chamber.height() as i64 + 3, 2);
+
+    'fall_loop: loop {
+        // Try jet push left/right 1 unit.
+        let jet_pos = (pos.0, pos.1 + pattern[*pattern_index].delta().x as i8);
+        *pattern_index = (*pattern_index + 1) % pattern.len();
+        if !check_shape(chamber, shape, jet_pos) {
+            pos = jet_pos;
+        }
+
+        // Try falling down 1 unit.
+        let fall_pos = (pos.0 - 1, pos.1);
+        if check_shape(chamber, shape, fall_pos) {
+            break 'fall_loop;
+        } else {
+            pos = fall_pos;
+        }
+    }
+
+    fill_shape(chamber, shape, pos);
+}
+
+// Returns the top 6 rows of the chamber, with the floor as filled rows if it
+// isn't that tall yet.
+fn get_top(chamber: &Grid<bool>) -> Vec<bool> {
+    let mut top: Vec<bool> = chamber.rows().rev().take(6).flatten().copied().collect();
+    top.resize(6 * WIDTH, true);
+    return top;
+}
+
+// Draws the chamber like the puzzle does, from the top down. Rows are
+// numbered upwards from the floor at 0, so frames line up as the tower grows.
+fn frame(chamber: &Grid<bool>) -> Frame {
+    let height = chamber.height();
+    let cells = Grid::from_fn(height, WIDTH, |(r, c)| {
+        if chamber[(height - 1 - r, c)] {
+            b'#'
+        } else {
+            b'.'
+        }
+    });
+    return Frame::new(-(height as i64), 0, cells);
+}
+
+fn shapes() -> [Shape; 5] {
+    [
+        // -
+        Shape {
+            width: 4,
+            height: 1,
+            cells: vec![(0, 0), (0, 1), (0, 2), (0, 3)],
+        },
+        // +
+        Shape {
+            width: 3,
+            height: 3,
+            cells: vec![(0, 1), (1, 0), (1, 1), (1, 2), (2, 1)],
+        },
+        // _|
+        Shape {
+            width: 3,
+            height: 3,
+            cells: vec![(0, 0), (0, 1), (0, 2), (1, 2), (2, 2)],
+        },
+        // |
+        Shape {
+            width: 1,
+            height: 4,
+            cells: vec![(0, 0), (1, 0), (2, 0), (3, 0)],
+        },
+        // (square)
+        Shape {
+            width: 2,
+            height: 2,
+            cells: vec![(0, 0), (0, 1), (1, 0), (1, 1)],
+        },
+    ]
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    // Jet pattern.
+    type Input = Vec<Direction>;
+
+    const PARAMS: &'static [Param] = &[Param {
+        name: "iterations",
+        default: 1000000000000,
+        min: 0,
+        max: 1000000000000000,
+        description: "Rocks dropped in part B",
+    }];
+
+    fn parse(input: &str) -> Result<Vec<Direction>, ParseError> {
+        let line = lines(input)
+            .next()
+            .filter(|l| !l.text.is_empty())
+            .ok_or_else(|| end_of_input(input, "a jet pattern"))?;
+
+        line.text
+            .chars()
+            .enumerate()
+            .map(|(i, c)| match c {
+                '<' => Ok(Direction::Left),
+                '>' => Ok(Direction::Right),
+                _ => Err(line.error_at_char(i, "a direction < or >")),
+            })
+            .collect()
+    }
+
+    fn part_a(pattern: &Vec<Direction>, ctx: &Context) -> Answer {
+        let shapes = shapes();
+
+        let mut chamber = Grid::new(0, WIDTH, false);
+        let mut pattern_index = 0;
+
+        for i in 0..2022 {
+            let shape = &shapes[i % shapes.len()];
+            drop_shape(&mut chamber, shape, pattern, &mut pattern_index);
+            ctx.frame(|| frame(&chamber));
+        }
+
+        return chamber.height().into();
+    }
+
+    fn part_b(pattern: &Vec<Direction>, ctx: &Context) -> Answer {
+        let shapes = shapes();
+        let iterations = ctx.param("iterations") as usize;
+
+        // Assumption: the shapes will fall into the chamber in a cycle, which we
+        // should be able to detect by when shape index, pattern index and the top
+        // of the chamber all repeat. The height can then be worked out from how
+        // many cycles we go through and which iteration we end on.
+        let history = cycle::simulate(
+            (Grid::new(0, WIDTH, false), 0),
+            iterations,
+            |(chamber, pattern_index), i| (i % shapes.len(), *pattern_index, get_top(chamber)),
+            |(chamber, _)| chamber.height() as i64,
+            |(chamber, pattern_index), i| {
+                let shape = &shapes[i % shapes.len()];
+                drop_shape(chamber, shape, pattern, pattern_index);
+                ctx.frame(|| frame(chamber));
+            },
+        );
+
+        // Report the cycle so that it can be checked, e.g. against the
+        // length of the jet pattern.
+        if let Some(cycle) = history.cycle() {
+            let mut stats = SearchStats::default();
+            stats.found_cycle(cycle);
+            ctx.report_search(&stats);
+        }
+
+        return history
+            .at(iterations)
+            .expect("should have run to the end or found a cycle")
+            .into();
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let pattern: String = (0..size * 100).map(|_| *rng.choose(&['<', '>'])).collect();
+        return Some(pattern + "\n");
+    }
+}
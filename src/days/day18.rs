@@ -0,0 +1,117 @@
+use std::collections::HashSet;
+use std::vec::Vec;
+
+use crate::parse::{end_of_input, lines};
+use crate::point::Point3;
+use crate::rng::Rng;
+use crate::scan::template;
+use crate::{Answer, Context, ParseError, Solution};
+
+fn surface_area(points: &HashSet<Point3>) -> usize {
+    return points
+        .iter()
+        .map(|p| p.neighbours_6().filter(|n| !points.contains(n)).count())
+        .sum();
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    // Cubes in the droplet.
+    type Input = HashSet<Point3>;
+
+    fn parse(input: &str) -> Result<HashSet<Point3>, ParseError> {
+        let points = lines(input)
+            .map(|line| {
+                let [x, y, z] = template(line, line.text, "{},{},{}")?;
+                return Ok(Point3::new(
+                    line.parse(x, "an x coordinate")?,
+                    line.parse(y, "a y coordinate")?,
+                    line.parse(z, "a z coordinate")?,
+                ));
+            })
+            .collect::<Result<HashSet<_>, ParseError>>()?;
+
+        if points.is_empty() {
+            return Err(end_of_input(input, "a cube"));
+        }
+        return Ok(points);
+    }
+
+    fn part_a(points: &HashSet<Point3>, _: &Context) -> Answer {
+        surface_area(points).into()
+    }
+
+    fn part_b(points: &HashSet<Point3>, _: &Context) -> Answer {
+        // Bounding box of the droplet, with a gap of 1 around it for the water
+        // to flow through.
+        let init_point = *points.iter().next().unwrap();
+        let gap = Point3::new(1, 1, 1);
+        let bounds = points
+            .iter()
+            .fold((init_point, init_point), |bound, &point| {
+                (bound.0.min_each(point - gap), bound.1.max_each(point + gap))
+            });
+        let in_bounds =
+            |p: Point3| p.min_each(bounds.0) == bounds.0 && p.max_each(bounds.1) == bounds.1;
+
+        let mut outer_flood_fill = HashSet::from([bounds.0]);
+        let mut search = Vec::from([bounds.0]);
+
+        while let Some(p) = search.pop() {
+            for dp in p.neighbours_6() {
+                if !in_bounds(dp) {
+                    continue;
+                }
+
+                if outer_flood_fill.contains(&dp) {
+                    continue;
+                }
+
+                if points.contains(&dp) {
+                    continue;
+                }
+
+                outer_flood_fill.insert(dp);
+                search.push(dp);
+            }
+        }
+
+        let total_area = surface_area(&outer_flood_fill);
+
+        let size = bounds.1 - bounds.0 + Point3::new(1, 1, 1);
+        let (x, y, z) = (size.x as usize, size.y as usize, size.z as usize);
+        let outer_area = 2 * (x * y + x * z + y * z);
+
+        let inner_area = total_area - outer_area;
+        return inner_area.into();
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        // A ball of cubes size across, with gaps that leave air pockets
+        // inside for part B.
+        let side = size.max(3) as i64;
+        let centre = (side - 1) as f64 / 2.0;
+        let mut cubes = Vec::new();
+        for x in 0..side {
+            for y in 0..side {
+                for z in 0..side {
+                    let dist = [x, y, z]
+                        .iter()
+                        .map(|&c| (c as f64 - centre).powi(2))
+                        .sum::<f64>()
+                        .sqrt();
+                    if dist <= side as f64 / 2.0 && rng.chance(0.85) {
+                        cubes.push(format!("{},{},{}\n", x, y, z));
+                    }
+                }
+            }
+        }
+        // Part B needs at least one cube.
+        if cubes.is_empty() {
+            cubes.push(format!("{},{},{}\n", side / 2, side / 2, side / 2));
+        }
+        rng.shuffle(&mut cubes);
+        return Some(cubes.concat());
+    }
+}
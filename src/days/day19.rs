@@ -0,0 +1,510 @@
+use std::cmp;
+use std::vec::Vec;
+
+use crate::parse::{lines, Line};
+use crate::rng::Rng;
+use crate::scan::ints;
+use crate::stats::SearchStats;
+use crate::{Answer, Context, Param, ParseError, Part, Solution};
+
+pub struct Blueprint {
+    // Cost in ore to build an ore robot.
+    ore_robot_cost: u16,
+    // Cost in ore to build a clay robot.
+    clay_robot_cost: u16,
+    // Cost in (ore, clay) to build an obsidian robot.
+    obsidian_robot_cost: (u16, u16),
+    // Cost in (ore, obsidian) to build a geode robot.
+    geode_robot_cost: (u16, u16),
+}
+
+impl Blueprint {
+    fn parse(line: Line) -> Result<Blueprint, ParseError> {
+        // The first number is the blueprint's ID, which is its position in the
+        // input anyway.
+        let s: Vec<u16> = ints(line, line.text, "a robot cost")?;
+
+        let [_, ore, clay, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian] = s[..] else {
+            return Err(line.error(line.text, "a blueprint with 6 robot costs"));
+        };
+
+        return Ok(Blueprint {
+            ore_robot_cost: ore,
+            clay_robot_cost: clay,
+            obsidian_robot_cost: (obsidian_ore, obsidian_clay),
+            geode_robot_cost: (geode_ore, geode_obsidian),
+        });
+    }
+}
+
+struct ResourceState {
+    amount: u16,
+    robots: u16,
+    // Optimization: whether this state passed on creating a robot for this
+    // resource and should not attempt to create a robot for this resource for
+    // future iterations (to reduce redundant/suboptimal states).
+    pass: bool,
+}
+
+impl ResourceState {
+    fn increment(&mut self) {
+        self.amount += self.robots;
+    }
+
+    fn no_pass(&self) -> ResourceState {
+        return ResourceState {
+            amount: self.amount,
+            robots: self.robots,
+            pass: false,
+        };
+    }
+}
+
+struct FactoryState {
+    ore: ResourceState,
+    clay: ResourceState,
+    obsidian: ResourceState,
+    geode: ResourceState,
+}
+
+// Finds the maximum number of geodes that can be opened in the given number of
+// minutes, counting the states searched into stats.
+fn max_geodes(blueprint: &Blueprint, minutes: usize, stats: &mut SearchStats) -> u16 {
+    let mut states = Vec::from([FactoryState {
+        ore: ResourceState {
+            amount: 0,
+            robots: 1,
+            pass: false,
+        },
+        clay: ResourceState {
+            amount: 0,
+            robots: 0,
+            pass: false,
+        },
+        obsidian: ResourceState {
+            amount: 0,
+            robots: 0,
+            pass: false,
+        },
+        geode: ResourceState {
+            amount: 0,
+            robots: 0,
+            pass: false,
+        },
+    }]);
+
+    let max_ore_cost = *[
+        blueprint.ore_robot_cost,
+        blueprint.clay_robot_cost,
+        blueprint.obsidian_robot_cost.0,
+        blueprint.geode_robot_cost.0,
+    ]
+    .iter()
+    .max()
+    .unwrap();
+
+    for minute in 0..minutes {
+        let mut new_states = Vec::new();
+
+        for state in &mut states {
+            stats.expand();
+            let mut build_ore_robot = false;
+            let mut build_clay_robot = false;
+            let mut build_obsidian_robot = false;
+            let mut build_geode_robot = false;
+            let mut only_geode_robot = false;
+
+            let afford_geode_robot = state.ore.amount >= blueprint.geode_robot_cost.0
+                && state.obsidian.amount >= blueprint.geode_robot_cost.1;
+            if afford_geode_robot
+                && state.ore.robots >= blueprint.geode_robot_cost.0
+                && state.obsidian.robots >= blueprint.geode_robot_cost.1
+            {
+                // A geode robot can be built every minute from here on, so
+                // building any other robot, or nothing, is left out.
+                only_geode_robot = true;
+                let others = [
+                    state.ore.amount >= blueprint.obsidian_robot_cost.0
+                        && state.clay.amount >= blueprint.obsidian_robot_cost.1,
+                    state.ore.amount >= blueprint.clay_robot_cost,
+                    state.ore.amount >= blueprint.ore_robot_cost,
+                ];
+                let skipped = 1 + others.iter().filter(|&&b| b).count();
+                stats.prune("geode robot first", skipped as u64);
+            } else {
+                if afford_geode_robot {
+                    if state.geode.pass {
+                        stats.prune("passed on geode robot", 1);
+                    } else {
+                        build_geode_robot = true;
+                    }
+                }
+                if state.ore.amount >= blueprint.obsidian_robot_cost.0
+                    && state.clay.amount >= blueprint.obsidian_robot_cost.1
+                {
+                    if state.obsidian.pass {
+                        stats.prune("passed on obsidian robot", 1);
+                    } else if state.obsidian.robots >= blueprint.geode_robot_cost.1 {
+                        stats.prune("enough obsidian robots", 1);
+                    } else {
+                        build_obsidian_robot = true;
+                    }
+                }
+                if state.ore.amount >= blueprint.clay_robot_cost {
+                    if state.clay.pass {
+                        stats.prune("passed on clay robot", 1);
+                    } else if state.clay.robots >= blueprint.obsidian_robot_cost.1 {
+                        stats.prune("enough clay robots", 1);
+                    } else {
+                        build_clay_robot = true;
+                    }
+                }
+                if state.ore.amount >= blueprint.ore_robot_cost {
+                    if state.ore.pass {
+                        stats.prune("passed on ore robot", 1);
+                    } else if state.ore.robots >= max_ore_cost {
+                        stats.prune("enough ore robots", 1);
+                    } else {
+                        build_ore_robot = true;
+                    }
+                }
+            }
+
+            state.ore.increment();
+            state.clay.increment();
+            state.obsidian.increment();
+            state.geode.increment();
+
+            if build_ore_robot {
+                state.ore.pass = true;
+            }
+            if build_clay_robot {
+                state.clay.pass = true;
+            }
+            if build_obsidian_robot {
+                state.obsidian.pass = true;
+            }
+            if build_geode_robot {
+                state.geode.pass = true;
+            }
+
+            if build_ore_robot {
+                new_states.push(FactoryState {
+                    ore: ResourceState {
+                        amount: state.ore.amount - blueprint.ore_robot_cost,
+                        robots: state.ore.robots + 1,
+                        pass: false,
+                    },
+                    clay: state.clay.no_pass(),
+                    obsidian: state.obsidian.no_pass(),
+                    geode: state.geode.no_pass(),
+                });
+            }
+
+            if build_clay_robot {
+                new_states.push(FactoryState {
+                    ore: ResourceState {
+                        amount: state.ore.amount - blueprint.clay_robot_cost,
+                        robots: state.ore.robots,
+                        pass: false,
+                    },
+                    clay: ResourceState {
+                        amount: state.clay.amount,
+                        robots: state.clay.robots + 1,
+                        pass: false,
+                    },
+                    obsidian: state.obsidian.no_pass(),
+                    geode: state.geode.no_pass(),
+                });
+                state.clay.pass = true;
+            }
+
+            if build_obsidian_robot {
+                new_states.push(FactoryState {
+                    ore: ResourceState {
+                        amount: state.ore.amount - blueprint.obsidian_robot_cost.0,
+                        robots: state.ore.robots,
+                        pass: false,
+                    },
+                    clay: ResourceState {
+                        amount: state.clay.amount - blueprint.obsidian_robot_cost.1,
+                        robots: state.clay.robots,
+                        pass: false,
+                    },
+                    obsidian: ResourceState {
+                        amount: state.obsidian.amount,
+                        robots: state.obsidian.robots + 1,
+                        pass: false,
+                    },
+                    geode: state.geode.no_pass(),
+                });
+            }
+
+            if build_geode_robot {
+                new_states.push(FactoryState {
+                    ore: ResourceState {
+                        amount: state.ore.amount - blueprint.geode_robot_cost.0,
+                        robots: state.ore.robots,
+                        pass: false,
+                    },
+                    clay: state.clay.no_pass(),
+                    obsidian: ResourceState {
+                        amount: state.obsidian.amount - blueprint.geode_robot_cost.1,
+                        robots: state.obsidian.robots,
+                        pass: false,
+                    },
+                    geode: ResourceState {
+                        amount: state.geode.amount,
+                        robots: state.geode.robots + 1,
+                        pass: false,
+                    },
+                });
+            }
+
+            if only_geode_robot {
+                state.ore.amount -= blueprint.geode_robot_cost.0;
+                state.obsidian.amount -= blueprint.geode_robot_cost.1;
+                state.geode.robots += 1;
+            }
+        }
+
+        states.append(&mut new_states);
+
+        // Geodes each state is sure to have by the end, building nothing more,
+        // and at most could have, building a geode robot every minute left.
+        let left = minutes - minute - 1;
+        let least = |s: &FactoryState| s.geode.amount as usize + s.geode.robots as usize * left;
+        let most = |s: &FactoryState| least(s) + left * left.saturating_sub(1) / 2;
+        let best = states.iter().map(least).max().unwrap();
+
+        states.retain(|state| {
+            if most(state) < best {
+                stats.prune("can't beat the best", 1);
+                return false;
+            }
+            // Having passed on every other robot, the only thing left to wait
+            // for is a geode robot, which needs obsidian robots to afford.
+            if state.ore.pass
+                && state.clay.pass
+                && state.obsidian.pass
+                && state.obsidian.robots == 0
+            {
+                stats.prune("passed on every robot", 1);
+                return false;
+            }
+            return true;
+        });
+
+        stats.frontier(states.len());
+    }
+
+    return states
+        .iter()
+        .map(|s| s.geode.amount)
+        .max_by(|x, y| x.cmp(y))
+        .unwrap();
+}
+
+// Like max_geodes, but trying every order of building robots, without the
+// caps on robots or skipping robots that were passed on. Between robots, the
+// factory only waits until it can afford the next one, as building a robot any
+// later than that can't leave more of anything.
+fn max_geodes_exhaustive(blueprint: &Blueprint, minutes: u16, stats: &mut SearchStats) -> u16 {
+    // Cost of each kind of robot in ore, clay and obsidian, with the kinds
+    // in the order ore, clay, obsidian, geode.
+    let costs = [
+        [blueprint.ore_robot_cost, 0, 0],
+        [blueprint.clay_robot_cost, 0, 0],
+        [
+            blueprint.obsidian_robot_cost.0,
+            blueprint.obsidian_robot_cost.1,
+            0,
+        ],
+        [
+            blueprint.geode_robot_cost.0,
+            0,
+            blueprint.geode_robot_cost.1,
+        ],
+    ];
+
+    // Goes on from a number of minutes left, with the robots of each kind,
+    // the ore, clay and obsidian there are and the geodes opened so far,
+    // keeping the most geodes found by the end. A branch that couldn't beat
+    // that even building a geode robot every minute left is cut, which can't
+    // lose the best.
+    fn search(
+        costs: &[[u16; 3]; 4],
+        (time_left, robots, amounts, geodes): (u16, [u16; 4], [u16; 3], u16),
+        best: &mut u16,
+        stats: &mut SearchStats,
+    ) {
+        stats.expand();
+
+        // Build nothing more, and let the geode robots carry on.
+        let done = geodes + robots[3] * time_left;
+        *best = cmp::max(*best, done);
+        if done + time_left * time_left.saturating_sub(1) / 2 <= *best {
+            stats.prune("can't beat the best", 1);
+            return;
+        }
+
+        // Geode robots first, as finding many geodes early cuts more.
+        for (kind, cost) in costs.iter().enumerate().rev() {
+            // Minutes until there's enough of each resource for the robot, or
+            // None if there are no robots collecting one it needs.
+            let wait = (0..3).try_fold(0, |wait, r| {
+                if amounts[r] >= cost[r] {
+                    Some(wait)
+                } else if robots[r] == 0 {
+                    None
+                } else {
+                    Some(cmp::max(wait, (cost[r] - amounts[r]).div_ceil(robots[r])))
+                }
+            });
+            // A robot built in the last minute would never collect anything.
+            let Some(wait) = wait else {
+                continue;
+            };
+            if wait + 1 >= time_left {
+                continue;
+            }
+
+            // Wait, then spend a minute building the robot.
+            let mut new_amounts = amounts;
+            for r in 0..3 {
+                new_amounts[r] = amounts[r] + robots[r] * (wait + 1) - cost[r];
+            }
+            let mut new_robots = robots;
+            new_robots[kind] += 1;
+
+            let state = (
+                time_left - wait - 1,
+                new_robots,
+                new_amounts,
+                geodes + robots[3] * (wait + 1),
+            );
+            search(costs, state, best, stats);
+        }
+    }
+
+    let mut best = 0;
+    search(&costs, (minutes, [1, 0, 0, 0], [0; 3], 0), &mut best, stats);
+    return best;
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = Vec<Blueprint>;
+
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "minutes",
+            default: 32,
+            min: 0,
+            max: 100,
+            description: "Minutes available in part B",
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Vec<Blueprint>, ParseError> {
+        lines(input).map(Blueprint::parse).collect()
+    }
+
+    fn part_a(blueprints: &Vec<Blueprint>, ctx: &Context) -> Answer {
+        let mut stats = SearchStats::default();
+        let quality: usize = blueprints
+            .iter()
+            .enumerate()
+            .map(|(i, b)| max_geodes(b, 24, &mut stats) as usize * (i + 1))
+            .sum();
+        ctx.report_search(&stats);
+        return quality.into();
+    }
+
+    fn part_b(blueprints: &Vec<Blueprint>, ctx: &Context) -> Answer {
+        let minutes = ctx.param("minutes") as usize;
+        let mut stats = SearchStats::default();
+        let quality: u64 = blueprints
+            .iter()
+            .take(3)
+            .map(|b| max_geodes(b, minutes, &mut stats) as u64)
+            .product();
+        ctx.report_search(&stats);
+        return quality.into();
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        // Costs in the same ranges as the real blueprints.
+        let blueprints = (1..=size)
+            .map(|id| {
+                format!(
+                    "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.\n",
+                    id,
+                    rng.range(2..=4),
+                    rng.range(2..=4),
+                    rng.range(2..=4),
+                    rng.range(5..=20),
+                    rng.range(2..=4),
+                    rng.range(5..=20)
+                )
+            })
+            .collect();
+        return Some(blueprints);
+    }
+
+    fn reference(part: Part, blueprints: &Vec<Blueprint>, ctx: &Context) -> Option<Answer> {
+        let mut stats = SearchStats::default();
+        let answer = match part {
+            Part::A => blueprints
+                .iter()
+                .enumerate()
+                .map(|(i, b)| max_geodes_exhaustive(b, 24, &mut stats) as u64 * (i as u64 + 1))
+                .sum::<u64>(),
+            Part::B => {
+                let minutes = ctx.param("minutes") as u16;
+                blueprints
+                    .iter()
+                    .take(3)
+                    .map(|b| max_geodes_exhaustive(b, minutes, &mut stats) as u64)
+                    .product()
+            }
+        };
+        ctx.report_search(&stats);
+        return Some(answer.into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("../../data/19a_sample-input.txt");
+
+    #[test]
+    fn reference_matches_max_geodes_on_the_sample() {
+        let blueprints = Day19::parse(SAMPLE).unwrap();
+        for (blueprint, expected) in blueprints.iter().zip([9, 12]) {
+            let mut stats = SearchStats::default();
+            assert_eq!(max_geodes(blueprint, 24, &mut stats), expected);
+            assert_eq!(max_geodes_exhaustive(blueprint, 24, &mut stats), expected);
+        }
+    }
+
+    // Blueprints where the best plan builds another robot while a geode robot
+    // could be built, or builds an obsidian robot after passing on every
+    // other one.
+    #[test]
+    fn max_geodes_keeps_the_best_plan() {
+        let blueprints = Day19::parse(
+            "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. \
+             Each obsidian robot costs 2 ore and 13 clay. Each geode robot costs 2 ore and 6 obsidian.\n\
+             Blueprint 2: Each ore robot costs 4 ore. Each clay robot costs 2 ore. \
+             Each obsidian robot costs 3 ore and 6 clay. Each geode robot costs 4 ore and 20 obsidian.\n",
+        )
+        .unwrap();
+        let mut stats = SearchStats::default();
+        assert_eq!(max_geodes(&blueprints[0], 24, &mut stats), 15);
+        assert_eq!(max_geodes(&blueprints[1], 24, &mut stats), 5);
+    }
+}
@@ -0,0 +1,137 @@
+use crate::parse::lines;
+use crate::rng::Rng;
+use crate::{Answer, Context, ParseError, Solution};
+
+#[derive(Clone)]
+enum Shape {
+    Rock = 1,
+    Paper = 2,
+    Scissors = 3,
+}
+
+enum Outcome {
+    Win = 6,
+    Draw = 3,
+    Lose = 0,
+}
+
+impl Shape {
+    fn from_char(c: char) -> Shape {
+        match c {
+            'A' | 'X' => Shape::Rock,
+            'B' | 'Y' => Shape::Paper,
+            'C' | 'Z' => Shape::Scissors,
+            _ => unreachable!("parse should have checked shape characters"),
+        }
+    }
+
+    fn for_outcome_against(o: &Outcome, s: &Shape) -> Shape {
+        match o {
+            Outcome::Win => match s {
+                Shape::Rock => Shape::Paper,
+                Shape::Paper => Shape::Scissors,
+                Shape::Scissors => Shape::Rock,
+            },
+            Outcome::Draw => s.clone(),
+            Outcome::Lose => match s {
+                Shape::Rock => Shape::Scissors,
+                Shape::Paper => Shape::Rock,
+                Shape::Scissors => Shape::Paper,
+            },
+        }
+    }
+
+    fn score_against(&self, s: &Shape) -> u32 {
+        (match self {
+            Shape::Rock => match s {
+                Shape::Rock => Outcome::Draw,
+                Shape::Paper => Outcome::Lose,
+                Shape::Scissors => Outcome::Win,
+            },
+            Shape::Paper => match s {
+                Shape::Rock => Outcome::Win,
+                Shape::Paper => Outcome::Draw,
+                Shape::Scissors => Outcome::Lose,
+            },
+            Shape::Scissors => match s {
+                Shape::Rock => Outcome::Lose,
+                Shape::Paper => Outcome::Win,
+                Shape::Scissors => Outcome::Draw,
+            },
+        }) as u32
+    }
+}
+
+impl Outcome {
+    fn from_char(c: char) -> Outcome {
+        match c {
+            'X' => Outcome::Lose,
+            'Y' => Outcome::Draw,
+            'Z' => Outcome::Win,
+            _ => unreachable!("parse should have checked outcome characters"),
+        }
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    // Each round of the strategy guide as (opponent's play, second column).
+    type Input = Vec<(char, char)>;
+
+    fn parse(input: &str) -> Result<Vec<(char, char)>, ParseError> {
+        lines(input)
+            .map(|line| {
+                let opponent = line.char_at(0, "opponent's play A, B or C")?;
+                if !('A'..='C').contains(&opponent) {
+                    return Err(line.error_at_char(0, "opponent's play A, B or C"));
+                }
+
+                let second = line.char_at(2, "second column X, Y or Z")?;
+                if !('X'..='Z').contains(&second) {
+                    return Err(line.error_at_char(2, "second column X, Y or Z"));
+                }
+
+                Ok((opponent, second))
+            })
+            .collect()
+    }
+
+    fn part_a(rounds: &Vec<(char, char)>, _: &Context) -> Answer {
+        let mut score = 0;
+
+        for &(opponent, you) in rounds {
+            let opponent = Shape::from_char(opponent);
+            let you = Shape::from_char(you);
+
+            score += you.score_against(&opponent) + you as u32;
+        }
+
+        return score.into();
+    }
+
+    fn part_b(rounds: &Vec<(char, char)>, _: &Context) -> Answer {
+        let mut score = 0;
+
+        for &(opponent, outcome) in rounds {
+            let opponent = Shape::from_char(opponent);
+            let outcome = Outcome::from_char(outcome);
+
+            let you = Shape::for_outcome_against(&outcome, &opponent);
+            score += you.score_against(&opponent) + you as u32;
+        }
+
+        return score.into();
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let rounds = (0..size)
+            .map(|_| {
+                let opponent = rng.choose(&['A', 'B', 'C']);
+                let second = rng.choose(&['X', 'Y', 'Z']);
+                format!("{} {}\n", opponent, second)
+            })
+            .collect();
+        return Some(rounds);
+    }
+}
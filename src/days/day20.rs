@@ -0,0 +1,109 @@
+use std::vec::Vec;
+
+use crate::parse::{end_of_input, lines};
+use crate::rng::Rng;
+use crate::{Answer, Context, Param, ParseError, Solution};
+
+struct Item {
+    value: isize,
+    index: usize,
+}
+
+// Mixes the numbers the given number of times and returns the sum of the
+// grove coordinates.
+fn mix(values: &[isize], key: isize, rounds: usize) -> isize {
+    let mut ring: Vec<Item> = values
+        .iter()
+        .enumerate()
+        .map(|(i, v)| Item {
+            value: v * key,
+            index: i,
+        })
+        .collect();
+
+    let len = ring.len() as isize;
+    let mut index = 0;
+
+    for i in 0..len * rounds as isize {
+        while ring[index].index != (i % len) as usize {
+            index = (index + 1) % len as usize;
+        }
+
+        let el = ring.remove(index);
+
+        let new_index = (index as isize + el.value).rem_euclid(len - 1);
+        ring.insert(new_index as usize, el);
+    }
+
+    let index = ring
+        .iter()
+        .position(|v| v.value == 0)
+        .expect("ring should contain 0");
+
+    return [1000, 2000, 3000]
+        .iter()
+        .map(|i| ring[(index + i) % len as usize].value)
+        .sum();
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    // The encrypted file.
+    type Input = Vec<isize>;
+
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "key",
+            default: 811589153,
+            min: -1000000000000,
+            max: 1000000000000,
+            description: "Decryption key in part B",
+        },
+        Param {
+            name: "rounds",
+            default: 10,
+            min: 0,
+            max: 1000000,
+            description: "Mixing rounds in part B",
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Vec<isize>, ParseError> {
+        let values = lines(input)
+            .map(|l| l.parse(l.text, "a number"))
+            .collect::<Result<Vec<isize>, _>>()?;
+
+        if !values.contains(&0) {
+            return Err(end_of_input(input, "a value of 0"));
+        }
+        return Ok(values);
+    }
+
+    fn part_a(values: &Vec<isize>, _: &Context) -> Answer {
+        mix(values, 1, 1).into()
+    }
+
+    fn part_b(values: &Vec<isize>, ctx: &Context) -> Answer {
+        let key = ctx.param("key") as isize;
+        let rounds = ctx.param("rounds") as usize;
+        mix(values, key, rounds).into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        // Mixing needs at least 2 numbers, and exactly one of them is 0.
+        let len = size.max(2);
+        let zero = rng.index(len);
+        let values = (0..len)
+            .map(|i| {
+                let value = if i == zero {
+                    0
+                } else {
+                    rng.range(1..=10000) * if rng.chance(0.5) { 1 } else { -1 }
+                };
+                format!("{}\n", value)
+            })
+            .collect();
+        return Some(values);
+    }
+}
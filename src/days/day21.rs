@@ -0,0 +1,548 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use crate::parse::{end_of_input, lines, Line};
+use crate::rng::Rng;
+use crate::scan::template;
+use crate::{Answer, Context, ParseError, Part, Solution};
+
+pub enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl Operator {
+    fn from(line: Line, s: &str) -> Result<Operator, ParseError> {
+        match s {
+            "+" => Ok(Operator::Add),
+            "-" => Ok(Operator::Subtract),
+            "*" => Ok(Operator::Multiply),
+            "/" => Ok(Operator::Divide),
+            _ => Err(line.error(s, "an operator +, -, * or /")),
+        }
+    }
+
+    fn operate(&self, op1: u64, op2: u64) -> u64 {
+        match self {
+            Operator::Add => op1 + op2,
+            Operator::Subtract => op1 - op2,
+            Operator::Multiply => op1 * op2,
+            Operator::Divide => op1 / op2,
+        }
+    }
+
+    // Like operate, but None if the result isn't a whole number that fits in
+    // a u64, or there's nothing to divide by.
+    fn checked_operate(&self, op1: u64, op2: u64) -> Option<u64> {
+        match self {
+            Operator::Add => op1.checked_add(op2),
+            Operator::Subtract => op1.checked_sub(op2),
+            Operator::Multiply => op1.checked_mul(op2),
+            Operator::Divide => op1.checked_div(op2),
+        }
+    }
+}
+
+pub struct Operation {
+    operator: Operator,
+    operands: [String; 2],
+}
+
+impl Operation {
+    fn from(line: Line, s: &str) -> Result<Operation, ParseError> {
+        let [op1, operator, op2] = template(line, s, "{} {} {}")?;
+
+        return Ok(Operation {
+            operator: Operator::from(line, operator)?,
+            operands: [String::from(op1), String::from(op2)],
+        });
+    }
+}
+
+pub enum Job {
+    Number(u64),
+    Operation(Operation),
+}
+
+impl Job {
+    fn from(line: Line, s: &str) -> Result<Job, ParseError> {
+        match s.parse::<u64>() {
+            Ok(v) => Ok(Job::Number(v)),
+            Err(_) if s.contains(' ') => Ok(Job::Operation(Operation::from(line, s)?)),
+            Err(_) => Err(line.error(s, "a number or an operation")),
+        }
+    }
+
+    fn evaluate(&self, monkeys: &HashMap<String, Job>) -> u64 {
+        match self {
+            Job::Number(v) => *v,
+            Job::Operation(o) => {
+                let op1 = monkeys
+                    .get(&o.operands[0])
+                    .expect("monkey should exist for left operand in evaluate")
+                    .evaluate(monkeys);
+                let op2 = monkeys
+                    .get(&o.operands[1])
+                    .expect("monkey should exist for right operand in evaluate")
+                    .evaluate(monkeys);
+                return o.operator.operate(op1, op2);
+            }
+        }
+    }
+
+    // Unwraps Operation from Job.
+    fn op(&self) -> &Operation {
+        match self {
+            Job::Number(_) => panic!("operation should have operation"),
+            Job::Operation(o) => o,
+        }
+    }
+}
+
+// Largest number the generator lets a monkey on the way to humn yell, and
+// largest product of the numbers humn's side multiplies and divides by. These
+// keep part A within a u64 when humn yells something else.
+const TARGET_LIMIT: u64 = 1_000_000_000_000;
+const SCALE_LIMIT: u64 = 1_000_000;
+
+// Monkeys made up by the generator, as lines of the puzzle input.
+struct Troop {
+    names: HashSet<String>,
+    jobs: Vec<String>,
+}
+
+impl Troop {
+    // A new monkey name. root and humn are taken from the start.
+    fn name(&mut self, rng: &mut Rng) -> String {
+        loop {
+            let name: String = (0..4)
+                .map(|_| (b'a' + rng.below(26) as u8) as char)
+                .collect();
+            if self.names.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+
+    fn add(&mut self, name: &str, job: String) {
+        self.jobs.push(format!("{}: {}\n", name, job));
+    }
+
+    // A new monkey that yells a number.
+    fn number(&mut self, rng: &mut Rng, value: u64) -> String {
+        let name = self.name(rng);
+        self.add(&name, value.to_string());
+        return name;
+    }
+
+    // A new monkey doing an operation on two others. The operands of + and *
+    // can go either way round.
+    fn operation(&mut self, rng: &mut Rng, left: &str, operator: &str, right: &str) -> String {
+        let name = self.name(rng);
+        let (left, right) = if matches!(operator, "+" | "*") && rng.chance(0.5) {
+            (right, left)
+        } else {
+            (left, right)
+        };
+        self.add(&name, format!("{} {} {}", left, operator, right));
+        return name;
+    }
+
+    // A new monkey whose job works out to exactly a value, which must be at
+    // least 1. Like the real inputs, nothing on the way goes below 0 or
+    // divides unevenly.
+    fn constant(&mut self, rng: &mut Rng, value: u64, depth: usize) -> String {
+        if value > 20 {
+            // Big numbers are q * k + r with small k and r, so that the tree
+            // only grows with the number of digits.
+            let k = rng.range(2..=9) as u64;
+            let (q, r) = (value / k, value % k);
+            let q = self.constant(rng, q, depth);
+            let k = self.number(rng, k);
+            let product = self.operation(rng, &q, "*", &k);
+            if r == 0 {
+                return product;
+            }
+            let r = self.number(rng, r);
+            return self.operation(rng, &product, "+", &r);
+        }
+
+        if depth == 0 || rng.chance(0.4) {
+            return self.number(rng, value);
+        }
+        let (left, operator, right) = match rng.index(4) {
+            0 if value >= 2 => {
+                let a = rng.range(1..=value as i64 - 1) as u64;
+                (a, "+", value - a)
+            }
+            1 => {
+                let b = rng.range(1..=10) as u64;
+                (value + b, "-", b)
+            }
+            2 if (2..value).any(|k| value.is_multiple_of(k)) => {
+                let divisors: Vec<u64> = (2..value).filter(|&k| value.is_multiple_of(k)).collect();
+                let k = *rng.choose(&divisors);
+                (value / k, "*", k)
+            }
+            _ => {
+                let b = rng.range(2..=5) as u64;
+                (value * b, "/", b)
+            }
+        };
+        let left = self.constant(rng, left, depth - 1);
+        let right = self.constant(rng, right, depth - 1);
+        return self.operation(rng, &left, operator, &right);
+    }
+}
+
+// Follows operands depth first from a monkey, noting each monkey that one of
+// its operands leads back around to. Monkeys that are being followed are
+// false in finished, and monkeys that have been are true.
+fn find_loops<'a>(
+    monkeys: &'a HashMap<String, Job>,
+    name: &'a str,
+    finished: &mut HashMap<&'a str, bool>,
+    loops: &mut HashSet<&'a str>,
+) {
+    finished.insert(name, false);
+    if let Some(Job::Operation(op)) = monkeys.get(name) {
+        for operand in &op.operands {
+            match finished.get(operand.as_str()) {
+                Some(false) => {
+                    loops.insert(name);
+                }
+                Some(true) => {}
+                None => find_loops(monkeys, operand, finished, loops),
+            }
+        }
+    }
+    finished.insert(name, true);
+}
+
+// Whether a monkey's number depends on humn's, remembering the answer for
+// each monkey on the way so that monkeys shared between operations are only
+// looked at once.
+fn depends_on_human<'a>(
+    monkeys: &'a HashMap<String, Job>,
+    name: &'a str,
+    memo: &mut HashMap<&'a str, bool>,
+) -> bool {
+    if name == "humn" {
+        return true;
+    }
+    if let Some(&depends) = memo.get(name) {
+        return depends;
+    }
+
+    let depends = match monkeys.get(name) {
+        Some(Job::Operation(op)) => op
+            .operands
+            .iter()
+            .any(|operand| depends_on_human(monkeys, operand, memo)),
+        _ => false,
+    };
+    memo.insert(name, depends);
+    return depends;
+}
+
+// Like evaluate, but remembers each monkey's number on the way, and notes the
+// operations that can't be worked out in u64s. Those monkeys, and the ones
+// that need their numbers, come out as None.
+fn checked_evaluate<'a>(
+    monkeys: &'a HashMap<String, Job>,
+    name: &'a str,
+    memo: &mut HashMap<&'a str, Option<u64>>,
+    failed: &mut HashSet<&'a str>,
+) -> Option<u64> {
+    if let Some(&value) = memo.get(name) {
+        return value;
+    }
+
+    let value = match monkeys.get(name) {
+        Some(Job::Number(v)) => Some(*v),
+        Some(Job::Operation(op)) => {
+            let op1 = checked_evaluate(monkeys, &op.operands[0], memo, failed);
+            let op2 = checked_evaluate(monkeys, &op.operands[1], memo, failed);
+            let value = op1
+                .zip(op2)
+                .and_then(|(a, b)| op.operator.checked_operate(a, b));
+            if op1.is_some() && op2.is_some() && value.is_none() {
+                failed.insert(name);
+            }
+            value
+        }
+        None => None,
+    };
+    memo.insert(name, value);
+    return value;
+}
+
+// What humn has to yell for root's two sides to match, found by working down
+// from root and undoing each operation on the way. Err names the monkey whose
+// operation can't be undone in whole numbers that fit in a u64.
+fn human_number(monkeys: &HashMap<String, Job>) -> Result<u64, &str> {
+    let mut depends = HashMap::new();
+    let mut memo = HashMap::new();
+    let mut failed = HashSet::new();
+
+    // root only compares its sides, so has no number to match until the side
+    // without humn has been worked out.
+    let mut target = None;
+    let mut name = "root";
+    while name != "humn" {
+        let op = monkeys.get(name).expect("monkey should exist").op();
+        let human_first = depends_on_human(monkeys, &op.operands[0], &mut depends);
+        let (human, other) = if human_first {
+            (&op.operands[0], &op.operands[1])
+        } else {
+            (&op.operands[1], &op.operands[0])
+        };
+        let operand = checked_evaluate(monkeys, other, &mut memo, &mut failed).ok_or(name)?;
+
+        let Some(value) = target else {
+            target = Some(operand);
+            name = human;
+            continue;
+        };
+        let undone = match op.operator {
+            Operator::Add => value.checked_sub(operand),
+            Operator::Subtract if human_first => value.checked_add(operand),
+            Operator::Subtract => operand.checked_sub(value),
+            Operator::Multiply if operand != 0 && value % operand == 0 => Some(value / operand),
+            Operator::Multiply => None,
+            Operator::Divide if human_first && operand != 0 => value.checked_mul(operand),
+            Operator::Divide if !human_first && value != 0 && operand % value == 0 => {
+                Some(operand / value)
+            }
+            Operator::Divide => None,
+        };
+        target = Some(undone.ok_or(name)?);
+        name = human;
+    }
+
+    return Ok(target.expect("humn should be below root"));
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    // Each monkey's job, keyed by name.
+    type Input = HashMap<String, Job>;
+
+    fn parse(input: &str) -> Result<HashMap<String, Job>, ParseError> {
+        lines(input)
+            .map(|line| {
+                let [name, job] = template(line, line.text, "{}: {}")?;
+                Ok((String::from(name), Job::from(line, job)?))
+            })
+            .collect()
+    }
+
+    fn part_a(monkeys: &HashMap<String, Job>, _: &Context) -> Answer {
+        let root_job = monkeys.get("root").expect("monkey named root should exist");
+        return root_job.evaluate(monkeys).into();
+    }
+
+    fn part_b(monkeys: &HashMap<String, Job>, _: &Context) -> Answer {
+        let humn = human_number(monkeys)
+            .unwrap_or_else(|name| panic!("monkey {}'s operation should be undoable", name));
+        return humn.into();
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut troop = Troop {
+            names: HashSet::from([String::from("root"), String::from("humn")]),
+            jobs: Vec::new(),
+        };
+
+        // Work down from root to humn through size operations. target is what
+        // the current monkey has to yell for root's sides to match in part B.
+        let mut target = rng.range(1000..=1_000_000_000) as u64;
+        let mut current = troop.name(rng);
+        let other_side = troop.constant(rng, target, 3);
+        troop.add("root", format!("{} + {}", current, other_side));
+
+        // humn is always on the side that goes up with it, as the first
+        // operand of - and /, so that part A can't go below 0 either.
+        // divisors is the product of what humn's side divides by, and scale
+        // also includes what it multiplies by.
+        let mut divisors = 1;
+        let mut scale = 1;
+        for i in 0..size {
+            let child = if i + 1 == size {
+                String::from("humn")
+            } else {
+                troop.name(rng)
+            };
+
+            let (operator, operand) = loop {
+                match rng.index(4) {
+                    0 if target >= 2 => {
+                        let operand = rng.range(1..=(target - 1).min(1000) as i64) as u64;
+                        target -= operand;
+                        break ("+", operand);
+                    }
+                    1 => {
+                        let operand = rng.range(1..=1000) as u64;
+                        target += operand;
+                        break ("-", operand);
+                    }
+                    2 if scale * 9 <= SCALE_LIMIT && (2..=9).any(|k| target.is_multiple_of(k)) => {
+                        let factors: Vec<u64> =
+                            (2..=9).filter(|&k| target.is_multiple_of(k)).collect();
+                        let operand = *rng.choose(&factors);
+                        target /= operand;
+                        scale *= operand;
+                        break ("*", operand);
+                    }
+                    3 if scale * 9 <= SCALE_LIMIT && target * 9 <= TARGET_LIMIT => {
+                        let operand = rng.range(2..=9) as u64;
+                        target *= operand;
+                        divisors *= operand;
+                        scale *= operand;
+                        break ("/", operand);
+                    }
+                    _ => {}
+                }
+            };
+
+            let operand = troop.constant(rng, operand, 3);
+            let (left, right) = if matches!(operator, "+" | "*") && rng.chance(0.5) {
+                (&operand, &child)
+            } else {
+                (&child, &operand)
+            };
+            troop.add(&current, format!("{} {} {}", left, operator, right));
+            current = child;
+        }
+
+        // target is now the answer to part B. For part A, humn yells a bigger
+        // number, differing from it by a multiple of every divisor on the way
+        // so that each division still comes out exact.
+        let humn = target + divisors * rng.range(1..=100) as u64;
+        troop.add("humn", humn.to_string());
+
+        rng.shuffle(&mut troop.jobs);
+        return Some(troop.jobs.concat());
+    }
+
+    fn validate(part: Part, raw: &str, monkeys: &HashMap<String, Job>) -> Vec<ParseError> {
+        let mut problems = Vec::new();
+
+        // Where each monkey's name and job are, to point at.
+        let mut jobs: HashMap<&str, (Line, &str, &str)> = HashMap::new();
+        let mut names = Vec::new();
+        for line in lines(raw) {
+            let [name, job] =
+                template(line, line.text, "{}: {}").expect("monkey should have parsed");
+            if jobs.insert(name, (line, name, job)).is_some() {
+                problems.push(line.error(name, "a name no other monkey has"));
+            }
+            names.push(name);
+
+            // Numbers have no spaces, so this only matches operations.
+            if let Ok([op1, _, op2]) = template(line, job, "{} {} {}") {
+                for operand in [op1, op2] {
+                    if !monkeys.contains_key(operand) {
+                        problems.push(line.error(operand, "the name of a monkey"));
+                    }
+                }
+            }
+        }
+
+        let mut finished = HashMap::new();
+        let mut loops = HashSet::new();
+        for &name in &names {
+            if !finished.contains_key(name) {
+                find_loops(monkeys, name, &mut finished, &mut loops);
+            }
+        }
+        for &name in &names {
+            if loops.remove(name) {
+                let (line, name, _) = jobs[name];
+                problems.push(line.error(name, "a monkey whose number doesn't need its own"));
+            }
+        }
+
+        if !monkeys.contains_key("root") {
+            problems.push(end_of_input(raw, "a monkey named root"));
+        }
+        if part == Part::B {
+            if !monkeys.contains_key("humn") {
+                problems.push(end_of_input(raw, "a monkey named humn"));
+            }
+            if let Some(&(line, _, job)) = jobs.get("root") {
+                if job.parse::<u64>().is_ok() {
+                    problems.push(line.error(job, "an operation for root to compare"));
+                }
+            }
+        }
+
+        // Part A works out root's number and every one it depends on.
+        if part == Part::A && problems.is_empty() {
+            let mut memo = HashMap::new();
+            let mut failed = HashSet::new();
+            checked_evaluate(monkeys, "root", &mut memo, &mut failed);
+            for &name in &names {
+                if failed.contains(name) {
+                    let (line, _, job) = jobs[name];
+                    problems.push(line.error(job, "an operation with a whole result in range"));
+                }
+            }
+        }
+
+        // Part B works its way down from root to humn, so humn should be on
+        // exactly one side of root and of each monkey on the way. That can
+        // only be worked out once every monkey's number can be.
+        if part == Part::B && problems.is_empty() {
+            let mut memo = HashMap::new();
+            if !depends_on_human(monkeys, "root", &mut memo) {
+                let (line, name, _) = jobs["humn"];
+                problems.push(line.error(name, "a monkey that root's number depends on"));
+            }
+            for &name in &names {
+                let (line, _, job) = jobs[name];
+                if let Some(Job::Operation(op)) = monkeys.get(name) {
+                    let [op1, op2] = &op.operands;
+                    if depends_on_human(monkeys, op1, &mut memo)
+                        && depends_on_human(monkeys, op2, &mut memo)
+                    {
+                        problems.push(line.error(job, "an operation with humn on one side only"));
+                    }
+                }
+            }
+        }
+
+        // The monkeys that don't depend on humn are worked out as in part A,
+        // and then each operation from root down to humn is undone.
+        if part == Part::B && problems.is_empty() {
+            let mut depends = HashMap::new();
+            let mut memo = HashMap::new();
+            let mut failed = HashSet::new();
+            for &name in &names {
+                if !depends_on_human(monkeys, name, &mut depends) {
+                    checked_evaluate(monkeys, name, &mut memo, &mut failed);
+                }
+            }
+            for &name in &names {
+                if failed.contains(name) {
+                    let (line, _, job) = jobs[name];
+                    problems.push(line.error(job, "an operation with a whole result in range"));
+                }
+            }
+
+            if problems.is_empty() {
+                if let Err(name) = human_number(monkeys) {
+                    let (line, _, job) = jobs[name];
+                    let expected = "an operation humn's number can be worked back through";
+                    problems.push(line.error(job, expected));
+                }
+            }
+        }
+
+        problems.sort_by_key(|e| (e.line, e.column));
+        return problems;
+    }
+}
@@ -0,0 +1,617 @@
+use std::collections::HashMap;
+use std::vec::Vec;
+
+use crate::grid::Grid;
+use crate::parse::{end_of_input, lines, Line};
+use crate::point::{Direction, Point3};
+use crate::rng::Rng;
+use crate::{Answer, Context, ParseError, Part, Solution};
+
+pub enum Move {
+    Forward(u8),
+    Turn(TurnDirection),
+}
+
+#[derive(Clone, Copy)]
+pub enum TurnDirection {
+    Right,
+    Left,
+}
+
+impl TurnDirection {
+    fn from(c: char) -> Option<TurnDirection> {
+        match c {
+            'R' => Some(TurnDirection::Right),
+            'L' => Some(TurnDirection::Left),
+            _ => None,
+        }
+    }
+
+    fn apply(&self, dir: Direction) -> Direction {
+        match self {
+            TurnDirection::Right => dir.turn_right(),
+            TurnDirection::Left => dir.turn_left(),
+        }
+    }
+}
+
+// Value of a facing in the password.
+fn facing_value(dir: Direction) -> usize {
+    match dir {
+        Direction::Right => 0,
+        Direction::Down => 1,
+        Direction::Left => 2,
+        Direction::Up => 3,
+    }
+}
+
+fn parse_path(line: Line) -> Result<Vec<Move>, ParseError> {
+    let mut path = Vec::new();
+    let mut steps: u8 = 0;
+
+    for (i, c) in line.text.chars().enumerate() {
+        if let Some(turn) = TurnDirection::from(c) {
+            if steps > 0 {
+                path.push(Move::Forward(steps));
+                steps = 0;
+            }
+            path.push(Move::Turn(turn));
+        } else if let Some(digit) = c.to_digit(10) {
+            steps = steps
+                .checked_mul(10)
+                .and_then(|s| s.checked_add(digit as u8))
+                .ok_or_else(|| line.error_at_char(i, "a number of steps up to 255"))?;
+        } else {
+            return Err(line.error_at_char(i, "a number of steps or a turn R or L"));
+        }
+    }
+
+    if steps > 0 {
+        path.push(Move::Forward(steps));
+    }
+
+    return Ok(path);
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    // Off the edge of the map.
+    Void,
+    Open,
+    Wall,
+}
+
+// Moves from a position one step in a direction, if that lands on a tile of
+// the map.
+fn step(map: &Grid<Tile>, pos: (usize, usize), dir: Direction) -> Option<(usize, usize)> {
+    map.step(pos, dir).filter(|&p| map[p] != Tile::Void)
+}
+
+// First tile of the map in an iterator over a row or column.
+fn first_tile<'a>(mut cells: impl Iterator<Item = &'a Tile>) -> usize {
+    cells
+        .position(|&t| t != Tile::Void)
+        .expect("row or column should have a tile")
+}
+
+// Password for a final (row, column) and facing, which count rows and columns
+// from 1.
+fn password(pos: (usize, usize), dir: Direction) -> usize {
+    (pos.0 + 1) * 1000 + 4 * (pos.1 + 1) + facing_value(dir)
+}
+
+#[derive(Clone)]
+struct State {
+    pos: (usize, usize),
+    dir: Direction,
+}
+
+// Splits the map into the six square faces of a cube net, giving the size of
+// a face and which tiles of the map (in units of faces) are faces, from the
+// top left.
+fn net(map: &Grid<Tile>) -> Option<(usize, Vec<(usize, usize)>)> {
+    let tiles = map.iter().filter(|&(_, &t)| t != Tile::Void).count();
+    let tile_size = ((tiles / 6) as f32).sqrt().round() as usize;
+    if tile_size == 0 || 6 * tile_size * tile_size != tiles {
+        return None;
+    }
+
+    let mut faces = Vec::new();
+    for row in 0..map.height().div_ceil(tile_size) {
+        for col in 0..map.width().div_ceil(tile_size) {
+            let corner = (row * tile_size, col * tile_size);
+            if map[corner] == Tile::Void {
+                continue;
+            }
+            for i in 0..tile_size {
+                for j in 0..tile_size {
+                    let pos = (corner.0 + i, corner.1 + j);
+                    if map.get(pos).is_none_or(|&t| t == Tile::Void) {
+                        return None;
+                    }
+                }
+            }
+            faces.push((row, col));
+        }
+    }
+
+    // With every face whole, six of them take up all the tiles.
+    if faces.len() != 6 {
+        return None;
+    }
+    return Some((tile_size, faces));
+}
+
+// Tile next to a tile of the map (in units of faces), if that's in the map.
+fn next_tile(tile: (usize, usize), dir: Direction) -> Option<(usize, usize)> {
+    let delta = dir.delta();
+    Some((
+        tile.0.checked_add_signed(delta.y as isize)?,
+        tile.1.checked_add_signed(delta.x as isize)?,
+    ))
+}
+
+// Side of a face of the net, as the face's tile and the direction out of the
+// face across the side.
+type Side = ((usize, usize), Direction);
+
+// Works out which sides of the net meet when it's folded up, without folding
+// it. Going clockwise round the outline of the net, the two sides at a corner
+// where three faces meet are glued together. That brings the corners at their
+// far ends together, and if those add up to three faces between them, the
+// sides there are glued next, and so on until the outline has been zipped
+// shut.
+fn glue_sides(faces: &[(usize, usize)]) -> Option<HashMap<Side, Side>> {
+    let is_face = |tile: &(usize, usize)| faces.contains(tile);
+
+    // Sides on the outline, each with how many faces meet at the corner at
+    // its end. The top of the first face is always on the outline.
+    let start = (faces[0], Direction::Up);
+    let mut outline: Vec<(Side, usize)> = Vec::new();
+    let (mut tile, mut out) = start;
+    loop {
+        let along = out.turn_right();
+        let ahead = next_tile(tile, along).filter(is_face);
+        let (next, corners) = match ahead {
+            None => ((tile, along), 1),
+            Some(ahead) => match next_tile(ahead, out).filter(is_face) {
+                Some(diagonal) => ((diagonal, along.reverse()), 3),
+                None => ((ahead, out), 2),
+            },
+        };
+        outline.push(((tile, out), corners));
+        (tile, out) = next;
+        if (tile, out) == start {
+            break;
+        }
+    }
+
+    let mut glued = HashMap::new();
+    while !outline.is_empty() {
+        let i = outline.iter().position(|&(_, corners)| corners == 3)?;
+        let j = (i + 1) % outline.len();
+        glued.insert(outline[i].0, outline[j].0);
+        glued.insert(outline[j].0, outline[i].0);
+
+        if outline.len() > 2 {
+            let before = (i + outline.len() - 1) % outline.len();
+            let corners = outline[before].1 + outline[j].1;
+            // No more than three faces meet at a corner of a cube.
+            if corners > 3 {
+                return None;
+            }
+            outline[before].1 = corners;
+        }
+        outline.remove(i.max(j));
+        outline.remove(i.min(j));
+    }
+
+    return Some(glued);
+}
+
+// How far along a side of a face a position on the face next to it is, going
+// clockwise round the face.
+fn along_side((i, j): (usize, usize), out: Direction, tile_size: usize) -> usize {
+    match out {
+        Direction::Up => j,
+        Direction::Right => i,
+        Direction::Down => tile_size - 1 - j,
+        Direction::Left => tile_size - 1 - i,
+    }
+}
+
+// Position on a face next to one of its sides, given how far along the side
+// it is.
+fn on_side(along: usize, out: Direction, tile_size: usize) -> (usize, usize) {
+    let end = tile_size - 1;
+    match out {
+        Direction::Up => (0, along),
+        Direction::Right => (along, end),
+        Direction::Down => (end, end - along),
+        Direction::Left => (end - along, 0),
+    }
+}
+
+// Where a face of the net goes when folded into a cube: which tile of the map
+// it is (in units of faces), the corner of the cube that its top left corner
+// goes to, the directions on the cube that right and down on the map go in,
+// and the direction out of the cube. Positions on the cube are doubled, so
+// that the centres of tiles are whole.
+#[derive(Clone, Copy)]
+struct Face {
+    tile: (usize, usize),
+    corner: Point3,
+    right: Point3,
+    down: Point3,
+    out: Point3,
+}
+
+impl Face {
+    fn direction(&self, dir: Direction) -> Point3 {
+        match dir {
+            Direction::Right => self.right,
+            Direction::Left => -self.right,
+            Direction::Down => self.down,
+            Direction::Up => -self.down,
+        }
+    }
+
+    // Position on the cube of the centre of a tile, given by its row and
+    // column within this face.
+    fn centre(&self, (i, j): (usize, usize)) -> Point3 {
+        self.corner + self.down * (2 * i as i64 + 1) + self.right * (2 * j as i64 + 1)
+    }
+
+    // Row and column within this face of the tile centred on a position on
+    // the cube, if that's on this face.
+    fn tile_at(&self, pos: Point3, tile_size: usize) -> Option<(usize, usize)> {
+        let offset = pos - self.corner;
+        let (i, j) = (offset.dot(self.down), offset.dot(self.right));
+        let side = 0..2 * tile_size as i64;
+        if offset.dot(self.out) != 0 || !side.contains(&i) || !side.contains(&j) {
+            return None;
+        }
+        return Some(((i / 2) as usize, (j / 2) as usize));
+    }
+}
+
+// Folds the net into a cube, starting from the face with the first tile and
+// working outwards, turning each face over the edge it shares with one that
+// has already been placed. Nothing if the faces don't cover every side of the
+// cube.
+fn fold(map: &Grid<Tile>, tile_size: usize) -> Option<Vec<Face>> {
+    let side = 2 * tile_size as i64;
+    let mut faces = vec![Face {
+        tile: (0, first_tile(map.row(0).iter()) / tile_size),
+        corner: Point3::ORIGIN,
+        right: Point3::new(1, 0, 0),
+        down: Point3::new(0, 1, 0),
+        out: Point3::new(0, 0, -1),
+    }];
+
+    let mut i = 0;
+    while i < faces.len() {
+        let face = faces[i];
+        i += 1;
+
+        for dir in Direction::ALL {
+            let delta = dir.delta();
+            let (Some(row), Some(col)) = (
+                face.tile.0.checked_add_signed(delta.y as isize),
+                face.tile.1.checked_add_signed(delta.x as isize),
+            ) else {
+                continue;
+            };
+            let on_map = map
+                .get((row * tile_size, col * tile_size))
+                .is_some_and(|&t| t != Tile::Void);
+            if !on_map || faces.iter().any(|f| f.tile == (row, col)) {
+                continue;
+            }
+
+            // The next face bends into the cube at the shared edge. Carrying
+            // on across the edge goes into the cube, against this face's way
+            // out, and the next face's way out is the way across the edge.
+            let (corner, right, down, out) = match dir {
+                Direction::Right => (
+                    face.corner + face.right * side,
+                    -face.out,
+                    face.down,
+                    face.right,
+                ),
+                Direction::Left => (
+                    face.corner - face.out * side,
+                    face.out,
+                    face.down,
+                    -face.right,
+                ),
+                Direction::Down => (
+                    face.corner + face.down * side,
+                    face.right,
+                    -face.out,
+                    face.down,
+                ),
+                Direction::Up => (
+                    face.corner - face.out * side,
+                    face.right,
+                    face.out,
+                    -face.down,
+                ),
+            };
+            faces.push(Face {
+                tile: (row, col),
+                corner: corner,
+                right: right,
+                down: down,
+                out: out,
+            });
+        }
+    }
+
+    let overlap = (1..faces.len()).any(|i| faces[..i].iter().any(|f| f.out == faces[i].out));
+    if faces.len() != 6 || overlap {
+        return None;
+    }
+    return Some(faces);
+}
+
+// Part B by walking on the folded cube itself, so that it works for any net.
+fn walk_cube(input: &Input) -> usize {
+    let map = &input.map;
+    let tiles = map.iter().filter(|&(_, &t)| t != Tile::Void).count();
+    let tile_size = ((tiles / 6) as f32).sqrt().round() as usize;
+    let faces = fold(map, tile_size).expect("map should be the net of a cube");
+    let on_map = |face: &Face, (i, j): (usize, usize)| {
+        (face.tile.0 * tile_size + i, face.tile.1 * tile_size + j)
+    };
+
+    let mut face = 0;
+    let mut pos = (0, first_tile(map.row(0).iter()) % tile_size);
+    let mut dir = Direction::Right;
+
+    for p in &input.path {
+        match p {
+            Move::Forward(s) => {
+                for _ in 0..*s {
+                    let here = faces[face].centre(pos);
+                    let heading = faces[face].direction(dir);
+                    let (next_face, next_pos, next_dir) =
+                        match faces[face].tile_at(here + heading * 2, tile_size) {
+                            Some(next_pos) => (face, next_pos, dir),
+                            None => {
+                                // Over the edge onto the face that's out that
+                                // way, heading back into the cube.
+                                let next_face = faces
+                                    .iter()
+                                    .position(|f| f.out == heading)
+                                    .expect("cube should have a face on each side");
+                                let next_pos = faces[next_face]
+                                    .tile_at(here + heading - faces[face].out, tile_size)
+                                    .expect("edge should lead onto the next face");
+                                let next_dir = Direction::ALL
+                                    .into_iter()
+                                    .find(|&d| faces[next_face].direction(d) == -faces[face].out)
+                                    .expect("faces should meet square on");
+                                (next_face, next_pos, next_dir)
+                            }
+                        };
+
+                    if map[on_map(&faces[next_face], next_pos)] == Tile::Wall {
+                        break;
+                    }
+                    (face, pos, dir) = (next_face, next_pos, next_dir);
+                }
+            }
+            Move::Turn(d) => {
+                dir = d.apply(dir);
+            }
+        }
+    }
+
+    return password(on_map(&faces[face], pos), dir);
+}
+
+pub struct Input {
+    map: Grid<Tile>,
+    path: Vec<Move>,
+}
+
+// Cube nets to generate maps from, as (row, column) of each face in a grid of
+// faces: the real inputs', the sample's, and a few others.
+const NETS: [[(usize, usize); 6]; 6] = [
+    [(0, 1), (0, 2), (1, 1), (2, 0), (2, 1), (3, 0)],
+    [(0, 2), (1, 0), (1, 1), (1, 2), (2, 2), (2, 3)],
+    [(0, 1), (1, 0), (1, 1), (1, 2), (2, 1), (3, 1)],
+    [(0, 0), (0, 1), (1, 1), (1, 2), (2, 2), (2, 3)],
+    [(0, 0), (0, 1), (0, 2), (1, 2), (1, 3), (1, 4)],
+    [(0, 0), (1, 0), (1, 1), (1, 2), (2, 2), (2, 3)],
+];
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        let mut lines = lines(input);
+
+        let map_lines: Vec<Line> = lines.by_ref().take_while(|l| !l.text.is_empty()).collect();
+        match map_lines.first() {
+            None => return Err(end_of_input(input, "a map")),
+            // Both parts start from the leftmost tile of the first row.
+            Some(line) if !line.text.contains(['.', '#']) => {
+                return Err(line.error(line.text, "a tile in the first row"));
+            }
+            Some(_) => {}
+        }
+
+        let map = Grid::from_lines_padded(map_lines, Tile::Void, |line, j, c| match c {
+            '.' => Ok(Tile::Open),
+            '#' => Ok(Tile::Wall),
+            ' ' => Ok(Tile::Void),
+            _ => Err(line.error_at_char(j, "a map tile \".\", \"#\" or \" \"")),
+        })?;
+
+        let path = match lines.next() {
+            Some(line) => parse_path(line)?,
+            None => return Err(end_of_input(input, "a path")),
+        };
+
+        return Ok(Input {
+            map: map,
+            path: path,
+        });
+    }
+
+    fn part_a(input: &Input, _: &Context) -> Answer {
+        let map = &input.map;
+
+        // Trace through path.
+        let mut pos = (0, first_tile(map.row(0).iter()));
+        let mut dir = Direction::Right;
+
+        for p in &input.path {
+            match p {
+                Move::Forward(s) => {
+                    for _ in 0..*s {
+                        // Off the edge of the map, wrap around to the other
+                        // side of the row or column.
+                        let new_pos = step(map, pos, dir).unwrap_or_else(|| match dir {
+                            Direction::Right => (pos.0, first_tile(map.row(pos.0).iter())),
+                            Direction::Left => (
+                                pos.0,
+                                map.width() - 1 - first_tile(map.row(pos.0).iter().rev()),
+                            ),
+                            Direction::Down => (first_tile(map.column(pos.1)), pos.1),
+                            Direction::Up => (
+                                map.height() - 1 - first_tile(map.column(pos.1).rev()),
+                                pos.1,
+                            ),
+                        });
+                        if map[new_pos] == Tile::Open {
+                            pos = new_pos;
+                        }
+                    }
+                }
+                Move::Turn(d) => {
+                    dir = d.apply(dir);
+                }
+            }
+        }
+
+        return password(pos, dir).into();
+    }
+
+    fn part_b(input: &Input, _: &Context) -> Answer {
+        let map = &input.map;
+        let mut state = State {
+            pos: (0, first_tile(map.row(0).iter())),
+            dir: Direction::Right,
+        };
+        let (tile_size, faces) = net(map).expect("map should be split into faces");
+        let glued = glue_sides(&faces).expect("map should be the net of a cube");
+
+        for p in &input.path {
+            match p {
+                Move::Forward(s) => {
+                    'forward_loop: for _ in 0..*s {
+                        let new_state = match step(map, state.pos, state.dir) {
+                            Some(pos) => State {
+                                pos: pos,
+                                dir: state.dir,
+                            },
+                            None => {
+                                // Over the side of the face onto the one it's
+                                // glued to, which runs the other way round.
+                                let (i, j) = state.pos;
+                                let tile = (i / tile_size, j / tile_size);
+                                let (next_tile, out) = glued[&(tile, state.dir)];
+                                let along = along_side(
+                                    (i % tile_size, j % tile_size),
+                                    state.dir,
+                                    tile_size,
+                                );
+                                let (i, j) = on_side(tile_size - 1 - along, out, tile_size);
+                                State {
+                                    pos: (next_tile.0 * tile_size + i, next_tile.1 * tile_size + j),
+                                    dir: out.reverse(),
+                                }
+                            }
+                        };
+
+                        if map[new_state.pos] == Tile::Open {
+                            state = new_state;
+                        } else {
+                            break 'forward_loop;
+                        }
+                    }
+                }
+                Move::Turn(d) => {
+                    state.dir = d.apply(state.dir);
+                }
+            }
+        }
+
+        return password(state.pos, state.dir).into();
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let face_size = size.max(2);
+        let net = rng.choose(&NETS);
+        let face_rows = net.iter().map(|&(r, _)| r).max().unwrap() + 1;
+        let face_cols = net.iter().map(|&(_, c)| c).max().unwrap() + 1;
+
+        let mut map = vec![vec![' '; face_cols * face_size]; face_rows * face_size];
+        for &(r, c) in net {
+            for i in 0..face_size {
+                for j in 0..face_size {
+                    map[r * face_size + i][c * face_size + j] =
+                        if rng.chance(0.1) { '#' } else { '.' };
+                }
+            }
+        }
+        // Both parts start on the leftmost tile of the first row, so it has to
+        // be open.
+        let first = map[0].iter().position(|&c| c != ' ').unwrap();
+        map[0][first] = '.';
+
+        let mut text = String::new();
+        for row in map {
+            text.push_str(row.iter().collect::<String>().trim_end());
+            text.push('\n');
+        }
+        text.push('\n');
+        for i in 0..size * 10 {
+            if i > 0 {
+                text.push(*rng.choose(&['L', 'R']));
+            }
+            text.push_str(&rng.range(1..=50).to_string());
+        }
+        text.push('\n');
+        return Some(text);
+    }
+
+    fn validate(part: Part, raw: &str, input: &Input) -> Vec<ParseError> {
+        if part == Part::A {
+            return Vec::new();
+        }
+
+        // The blank line after the map, as the problem is with the map as a
+        // whole.
+        let end = lines(raw).nth(input.map.height()).unwrap();
+        let Some((tile_size, faces)) = net(&input.map) else {
+            return vec![end.error_at_end("a map of 6 square faces")];
+        };
+        if fold(&input.map, tile_size).is_none() || glue_sides(&faces).is_none() {
+            return vec![end.error_at_end("a map that folds up into a cube")];
+        }
+        return Vec::new();
+    }
+
+    fn reference(part: Part, input: &Input, _: &Context) -> Option<Answer> {
+        match part {
+            Part::A => None,
+            Part::B => Some(walk_cube(input).into()),
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use std::cmp;
+use std::collections::{HashMap, HashSet};
+
+use crate::frames::Frame;
+use crate::grid::Grid;
+use crate::parse::end_of_input;
+use crate::point::{Direction, Point2};
+use crate::rng::Rng;
+use crate::{Answer, Context, ParseError, Solution};
+
+// Directions the elves consider moving in, in their order for the first
+// round.
+const PROPOSALS: [Direction; 4] = [
+    Direction::Up,
+    Direction::Down,
+    Direction::Left,
+    Direction::Right,
+];
+
+struct Bound {
+    min: Point2,
+    max: Point2,
+}
+
+fn get_bounds(elves: &HashSet<Point2>) -> Bound {
+    let first = *elves.iter().next().unwrap();
+
+    return elves.iter().fold(
+        Bound {
+            min: first,
+            max: first,
+        },
+        |acc, p| Bound {
+            min: Point2::new(cmp::min(acc.min.x, p.x), cmp::min(acc.min.y, p.y)),
+            max: Point2::new(cmp::max(acc.max.x, p.x), cmp::max(acc.max.y, p.y)),
+        },
+    );
+}
+
+// Draws the smallest rectangle containing all the elves, like the puzzle
+// does.
+fn frame(elves: &HashSet<Point2>) -> Frame {
+    if elves.is_empty() {
+        return Frame::new(0, 0, Grid::new(0, 0, b'.'));
+    }
+
+    let bound = get_bounds(elves);
+    let cells = Grid::from_fn(
+        (bound.max.y - bound.min.y + 1) as usize,
+        (bound.max.x - bound.min.x + 1) as usize,
+        |(r, c)| {
+            let p = Point2::new(bound.min.x + c as i64, bound.min.y + r as i64);
+            if elves.contains(&p) {
+                b'#'
+            } else {
+                b'.'
+            }
+        },
+    );
+    return Frame::new(bound.min.y, bound.min.x, cells);
+}
+
+fn get_elf_dest(elves: &HashSet<Point2>, elf: Point2, round: usize) -> Option<Point2> {
+    if !elf.neighbours_8().any(|p| elves.contains(&p)) {
+        return None;
+    }
+
+    for i in 0..PROPOSALS.len() {
+        let dir = PROPOSALS[(i + round) % PROPOSALS.len()];
+        let dest = elf + dir.delta();
+
+        // The elf only moves if nobody is in that direction or diagonally
+        // either side of it.
+        let blocked = [
+            dest,
+            dest + dir.turn_left().delta(),
+            dest + dir.turn_right().delta(),
+        ]
+        .iter()
+        .any(|p| elves.contains(p));
+        if !blocked {
+            return Some(dest);
+        }
+    }
+    return None;
+}
+
+fn move_elves(elves: &mut HashSet<Point2>, round: usize) -> bool {
+    let mut move_map = HashMap::new();
+    let mut dest_map = HashMap::new();
+
+    for &elf in elves.iter() {
+        if let Some(dest) = get_elf_dest(elves, elf, round) {
+            move_map.insert(elf, dest);
+            dest_map.insert(
+                dest,
+                match dest_map.get(&dest) {
+                    Some(v) => v + 1,
+                    None => 1,
+                },
+            );
+        }
+    }
+
+    if move_map.is_empty() {
+        return false;
+    }
+
+    for (elf, dest) in move_map {
+        if *dest_map.get(&dest).unwrap() == 1 {
+            elves.remove(&elf);
+            elves.insert(dest);
+        }
+    }
+
+    return true;
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    // Positions of the elves, with x as the column and y as the row.
+    type Input = HashSet<Point2>;
+
+    fn parse(input: &str) -> Result<HashSet<Point2>, ParseError> {
+        let map = Grid::parse(input, |line, j, c| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(line.error_at_char(j, "an elf \"#\" or ground \".\"")),
+        })?;
+
+        let elves: HashSet<Point2> = map
+            .iter()
+            .filter(|&(_, &elf)| elf)
+            .map(|((i, j), _)| Point2::new(j as i64, i as i64))
+            .collect();
+        // There's no rectangle around no elves for part A to measure.
+        if elves.is_empty() {
+            return Err(end_of_input(input, "an elf \"#\""));
+        }
+
+        return Ok(elves);
+    }
+
+    fn part_a(elves: &HashSet<Point2>, ctx: &Context) -> Answer {
+        let mut elves = elves.clone();
+        ctx.frame(|| frame(&elves));
+
+        for i in 0..10 {
+            move_elves(&mut elves, i);
+            ctx.frame(|| frame(&elves));
+        }
+
+        let bound = get_bounds(&elves);
+        let area = (bound.max.x - bound.min.x + 1) * (bound.max.y - bound.min.y + 1);
+        let tiles = area as usize - elves.len();
+        return tiles.into();
+    }
+
+    fn part_b(elves: &HashSet<Point2>, ctx: &Context) -> Answer {
+        let mut elves = elves.clone();
+        ctx.frame(|| frame(&elves));
+
+        let mut round = 0;
+        while move_elves(&mut elves, round) {
+            ctx.frame(|| frame(&elves));
+            round += 1;
+        }
+
+        return (round + 1).into();
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let size = size.max(1);
+        let mut map = Grid::from_fn(size, size, |_| rng.chance(0.5));
+        // Small maps can come out empty, so one place always has an elf.
+        let pos = (rng.index(size), rng.index(size));
+        map[pos] = true;
+
+        return Some(map.render(|&elf| if elf { '#' } else { '.' }));
+    }
+}
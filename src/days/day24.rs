@@ -0,0 +1,375 @@
+use std::vec::Vec;
+
+use crate::frames::Frame;
+use crate::grid::{from_point, to_point, Grid};
+use crate::parse::{end_of_input, lines, Line};
+use crate::point::Point2;
+use crate::rng::Rng;
+use crate::search::{astar, Graph, Search};
+use crate::{Answer, Context, ParseError, Part, Solution};
+
+#[derive(Clone, Default)]
+struct Cell {
+    north: bool,
+    east: bool,
+    south: bool,
+    west: bool,
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+// The valley over time. Nodes are a position in the valley and the time
+// modulo the period after which the blizzards repeat.
+struct Valley<'a> {
+    blizzard_map: &'a Grid<Cell>,
+    start: Point2,
+    end: Point2,
+    period: usize,
+}
+
+impl<'a> Valley<'a> {
+    fn new(input: &'a Input, start: Point2, end: Point2) -> Valley<'a> {
+        let num_rows = input.blizzard_map.height();
+        let num_cols = input.blizzard_map.width();
+        let period = num_rows / gcd(num_rows, num_cols).max(1) * num_cols;
+
+        return Valley {
+            blizzard_map: &input.blizzard_map,
+            start: start,
+            end: end,
+            period: period.max(1),
+        };
+    }
+
+    // Whether a position is somewhere that can be stood on at a time.
+    fn is_free(&self, pos: Point2, time: usize) -> bool {
+        if pos == self.start || pos == self.end {
+            return true;
+        }
+        match from_point(pos - Point2::new(1, 1)) {
+            Some(cell) if self.blizzard_map.contains(cell) => {
+                let cell = self.blizzards(cell, time);
+                !(cell.north || cell.east || cell.south || cell.west)
+            }
+            _ => false,
+        }
+    }
+
+    // Blizzards in a cell of the blizzard map at a time, found by looking back
+    // to where each one that could be there started.
+    fn blizzards(&self, (i, j): (usize, usize), time: usize) -> Cell {
+        let (i, j, t) = (i as isize, j as isize, time as isize);
+        Cell {
+            north: self.blizzard_map.get_wrapping(i + t, j).north,
+            east: self.blizzard_map.get_wrapping(i, j - t).east,
+            south: self.blizzard_map.get_wrapping(i - t, j).south,
+            west: self.blizzard_map.get_wrapping(i, j + t).west,
+        }
+    }
+
+    // Draws the valley at a time with the expedition in it, like the puzzle
+    // does.
+    fn frame(&self, expedition: Point2, time: usize) -> Frame {
+        let num_rows = self.blizzard_map.height();
+        let num_cols = self.blizzard_map.width();
+
+        let cells = Grid::from_fn(num_rows + 2, num_cols + 2, |pos| {
+            let point = to_point(pos);
+            if point == expedition {
+                return b'E';
+            }
+            if point == self.start || point == self.end {
+                return b'.';
+            }
+            if pos.0 == 0 || pos.0 == num_rows + 1 || pos.1 == 0 || pos.1 == num_cols + 1 {
+                return b'#';
+            }
+
+            let cell = self.blizzards((pos.0 - 1, pos.1 - 1), time);
+            let count = [cell.north, cell.east, cell.south, cell.west]
+                .iter()
+                .filter(|&&b| b)
+                .count();
+            match count {
+                0 => b'.',
+                1 if cell.north => b'^',
+                1 if cell.east => b'>',
+                1 if cell.south => b'v',
+                1 => b'<',
+                n => b'0' + n as u8,
+            }
+        });
+        return Frame::new(0, 0, cells);
+    }
+}
+
+impl Graph for Valley<'_> {
+    type Node = (Point2, usize);
+
+    fn neighbours(&self, &(pos, time): &(Point2, usize)) -> Vec<(Self::Node, u64)> {
+        // Waiting in place is a move too.
+        let next_time = (time + 1) % self.period;
+        return [pos]
+            .into_iter()
+            .chain(pos.neighbours_4())
+            .filter(|&next| self.is_free(next, next_time))
+            .map(|next| ((next, next_time), 1))
+            .collect();
+    }
+
+    fn heuristic(&self, &(pos, _): &(Point2, usize)) -> u64 {
+        pos.manhattan(self.end)
+    }
+}
+
+// Search for the quickest way across a valley, setting off at a time. Its
+// path is the way across, if there is one.
+fn crossing(valley: &Valley, time: u64) -> Search<(Point2, usize)> {
+    astar(
+        valley,
+        [(valley.start, (time % valley.period as u64) as usize)],
+        |&(pos, _)| pos == valley.end,
+    )
+}
+
+// Number of steps to get from start to end, setting off at a time.
+fn trip(input: &Input, start: Point2, end: Point2, time: u64, ctx: &Context) -> u64 {
+    let valley = Valley::new(input, start, end);
+    let search = crossing(&valley, time);
+    ctx.report_search(search.stats());
+    let path = search
+        .path()
+        .expect("should have found a way across the valley");
+
+    // Apart from on the first trip, the start is where the last trip ended and
+    // has already been drawn.
+    for &(pos, t) in path.nodes.iter().skip(if time == 0 { 0 } else { 1 }) {
+        ctx.frame(|| valley.frame(pos, t));
+    }
+
+    return path.cost;
+}
+
+// The first of the trips a part makes that the blizzards block for good, as
+// its (start, end), if there is one.
+fn blocked_trip(input: &Input, part: Part) -> Option<(Point2, Point2)> {
+    let there = (input.start, input.end);
+    let back = (input.end, input.start);
+    let trips = match part {
+        Part::A => vec![there],
+        Part::B => vec![there, back, there],
+    };
+
+    let mut time = 0;
+    for (start, end) in trips {
+        match crossing(&Valley::new(input, start, end), time).path() {
+            Some(path) => time += path.cost,
+            None => return Some((start, end)),
+        }
+    }
+    return None;
+}
+
+pub struct Input {
+    // Blizzards inside the walls of the valley. Positions in the valley
+    // (including start and end) are 1 more than their position in this.
+    blizzard_map: Grid<Cell>,
+    start: Point2,
+    end: Point2,
+}
+
+// Checks that the top or bottom wall is as wide as the valley and all wall
+// but for one opening above or below the ground.
+fn check_wall(line: Line, width: usize, problems: &mut Vec<ParseError>) {
+    let mut opened = false;
+    for (j, c) in line.text.chars().enumerate() {
+        if j >= width {
+            problems.push(line.error_at_char(j, "the end of the wall"));
+            break;
+        }
+        if c == '.' && !opened && j > 0 && j + 1 < width {
+            opened = true;
+        } else if c != '#' {
+            problems.push(line.error_at_char(j, "wall \"#\""));
+        }
+    }
+    if line.text.chars().count() < width {
+        problems.push(line.error_at_end("a wall as wide as the valley"));
+    }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        // First line contains start position, and the last line the end
+        // position. Everything in between is the valley.
+        let mut rows: Vec<Line> = lines(input).collect();
+        let bottom = rows
+            .pop()
+            .ok_or_else(|| end_of_input(input, "the top wall of the valley"))?;
+        if rows.is_empty() {
+            return Err(end_of_input(input, "the bottom wall of the valley"));
+        }
+        let top = rows.remove(0);
+
+        let start = to_point((
+            0,
+            top.text
+                .find('.')
+                .ok_or_else(|| top.error(top.text, "an opening in the top wall"))?,
+        ));
+        let end = to_point((
+            rows.len() + 1,
+            bottom
+                .text
+                .find('.')
+                .ok_or_else(|| bottom.error(bottom.text, "an opening in the bottom wall"))?,
+        ));
+
+        let valley = Grid::from_lines(rows, |line, j, c| {
+            // The walls on either side aren't part of the blizzard map.
+            if j == 0 || j + 1 == line.text.chars().count() {
+                return Ok(Cell::default());
+            }
+            match c {
+                '.' | '^' | '>' | 'v' | '<' => Ok(Cell {
+                    north: c == '^',
+                    east: c == '>',
+                    south: c == 'v',
+                    west: c == '<',
+                }),
+                _ => Err(line.error_at_char(j, "ground or a blizzard")),
+            }
+        })?;
+        let blizzard_map = Grid::from_fn(
+            valley.height(),
+            valley.width().saturating_sub(2),
+            |(r, c)| valley[(r, c + 1)].clone(),
+        );
+
+        return Ok(Input {
+            blizzard_map: blizzard_map,
+            start: start,
+            end: end,
+        });
+    }
+
+    fn part_a(input: &Input, ctx: &Context) -> Answer {
+        trip(input, input.start, input.end, 0, ctx).into()
+    }
+
+    fn part_b(input: &Input, ctx: &Context) -> Answer {
+        let mut steps = 0;
+        steps += trip(input, input.start, input.end, steps, ctx);
+        steps += trip(input, input.end, input.start, steps, ctx);
+        steps += trip(input, input.start, input.end, steps, ctx);
+
+        return steps.into();
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let num_rows = size.max(3);
+        // Real valleys are about 5 times as wide as they are tall.
+        let num_cols = 5 * num_rows - 1;
+        let start = rng.index(num_cols) + 1;
+        let end = rng.index(num_cols) + 1;
+
+        loop {
+            let mut text = String::new();
+            let wall = |opening: usize| -> String {
+                (0..num_cols + 2)
+                    .map(|j| if j == opening { '.' } else { '#' })
+                    .collect()
+            };
+            text.push_str(&wall(start));
+            text.push('\n');
+            for _ in 0..num_rows {
+                text.push('#');
+                for j in 1..=num_cols {
+                    // Nothing blows up or down through the columns with the
+                    // openings, as in the real inputs, so never into them.
+                    let blizzards: &[char] = if j == start || j == end {
+                        &['>', '<']
+                    } else {
+                        &['^', '>', 'v', '<']
+                    };
+                    text.push(if rng.chance(0.4) {
+                        *rng.choose(blizzards)
+                    } else {
+                        '.'
+                    });
+                }
+                text.push_str("#\n");
+            }
+            text.push_str(&wall(end));
+            text.push('\n');
+
+            // Blizzards can cut off the way, so check that all three trips of
+            // part B can be made.
+            let input = Self::parse(&text).expect("generated valley should parse");
+            let possible = blocked_trip(&input, Part::B).is_none();
+            if possible {
+                return Some(text);
+            }
+        }
+    }
+
+    fn validate(part: Part, raw: &str, input: &Input) -> Vec<ParseError> {
+        let mut problems = Vec::new();
+        let all_lines: Vec<Line> = lines(raw).collect();
+        let width = input.blizzard_map.width() + 2;
+        let bottom = input.end.y as usize;
+
+        // Without any rows there's no telling how wide the walls should be.
+        if bottom == 1 {
+            return vec![all_lines[1].error_at_char(1, "a row of the valley")];
+        }
+
+        let mut openings = vec![input.start.x as usize, input.end.x as usize];
+        openings.dedup();
+
+        check_wall(all_lines[0], width, &mut problems);
+        for &line in &all_lines[1..bottom] {
+            for j in [0, width - 1] {
+                if line.text.chars().nth(j) != Some('#') {
+                    problems.push(line.error_at_char(j, "wall \"#\""));
+                }
+            }
+
+            // Blizzards going up or down would leave the valley through the
+            // openings, rather than wrapping around to the other side.
+            for &j in &openings {
+                if let Some(c @ ('^' | 'v')) = line.text.chars().nth(j) {
+                    problems.push(
+                        line.error_at_char(
+                            j,
+                            &format!("no blizzard {:?} in line with an opening", c),
+                        ),
+                    );
+                }
+            }
+        }
+        check_wall(all_lines[bottom], width, &mut problems);
+
+        if problems.is_empty() {
+            if let Some((_, end)) = blocked_trip(input, part) {
+                let line = all_lines[end.y as usize];
+                problems.push(line.error_at_char(
+                    end.x as usize,
+                    "an opening that can be reached through the blizzards",
+                ));
+            }
+        }
+
+        return problems;
+    }
+}
@@ -0,0 +1,84 @@
+use crate::parse::lines;
+use crate::rng::Rng;
+use crate::{Answer, Context, ParseError, Part, Solution};
+
+fn snafu_to_dec(s: &str) -> i64 {
+    let mut acc = 0;
+    for c in s.chars() {
+        acc = 5 * acc
+            + match c {
+                '2' => 2,
+                '1' => 1,
+                '0' => 0,
+                '-' => -1,
+                '=' => -2,
+                _ => unreachable!("parse should have checked SNAFU digits"),
+            };
+    }
+
+    return acc;
+}
+
+fn dec_to_snafu(mut v: i64) -> String {
+    let mut acc = String::new();
+
+    while v > 0 {
+        let rem = v % 5;
+        v /= 5;
+
+        acc.push(match rem {
+            0 => '0',
+            1 => '1',
+            2 => '2',
+            3 => {
+                v += 1;
+                '='
+            }
+            4 => {
+                v += 1;
+                '-'
+            }
+            _ => unreachable!("rem must be less than 5"),
+        });
+    }
+
+    return acc.chars().rev().collect();
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    // Fuel requirements, in SNAFU.
+    type Input = Vec<String>;
+
+    const PARTS: &'static [Part] = &[Part::A];
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        lines(input)
+            .map(|line| match line.text.find(|c| !"=-012".contains(c)) {
+                // Everything before i is ASCII, so i is also a char index.
+                Some(i) => Err(line.error_at_char(i, "a SNAFU digit =, -, 0, 1 or 2")),
+                None => Ok(line.text.to_string()),
+            })
+            .collect()
+    }
+
+    fn part_a(numbers: &Vec<String>, _: &Context) -> Answer {
+        let sum = numbers.iter().map(|l| snafu_to_dec(l)).sum();
+        return dec_to_snafu(sum).into();
+    }
+
+    fn part_b(_: &Vec<String>, _: &Context) -> Answer {
+        unreachable!("day 25 only has part A");
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut text = String::new();
+        for _ in 0..size {
+            let digits = rng.range(1..=19) as u32;
+            text.push_str(&dec_to_snafu(rng.range(1..=5i64.pow(digits))));
+            text.push('\n');
+        }
+        return Some(text);
+    }
+}
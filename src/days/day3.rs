@@ -0,0 +1,151 @@
+use std::collections::HashSet;
+
+use crate::parse::{end_of_input, lines};
+use crate::rng::Rng;
+use crate::{Answer, Context, ParseError, Part, Solution};
+
+fn item_priority(item: u8) -> u32 {
+    if item.is_ascii_lowercase() {
+        (item - b'a' + 1).into()
+    } else if item.is_ascii_uppercase() {
+        (item - b'A' + 27).into()
+    } else {
+        unreachable!("parse should have checked items")
+    }
+}
+
+// Every item type, in priority order.
+const ITEMS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    // Contents of each rucksack.
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        lines(input)
+            .map(|line| {
+                match line.text.find(|c: char| !c.is_ascii_alphabetic()) {
+                    // Everything before i is ASCII, so i is also a char index.
+                    Some(i) => Err(line.error_at_char(i, "an item letter")),
+                    None => Ok(line.text.to_string()),
+                }
+            })
+            .collect()
+    }
+
+    fn part_a(rucksacks: &Vec<String>, _: &Context) -> Answer {
+        let mut sum = 0;
+
+        'line_loop: for line in rucksacks {
+            let (left, right) = line.split_at(line.len() / 2);
+
+            let mut items = HashSet::new();
+
+            for b in left.bytes() {
+                items.insert(b);
+            }
+            for b in right.bytes() {
+                if items.contains(&b) {
+                    sum += item_priority(b);
+                    continue 'line_loop;
+                }
+            }
+        }
+
+        return sum.into();
+    }
+
+    fn part_b(rucksacks: &Vec<String>, _: &Context) -> Answer {
+        let mut sum = 0;
+
+        'group_loop: for group in rucksacks.chunks(3) {
+            // Process line 1 of group by adding its contents to a set.
+            let mut items = HashSet::new();
+
+            for b in group[0].bytes() {
+                items.insert(b);
+            }
+
+            // Process line 2 of group by paring down set to the common items.
+            let line = group.get(1).expect("group should have 3 rucksacks");
+            items.retain(|b| line.as_bytes().contains(b));
+
+            // Process line 3 of group by finding common item.
+            let line = group.get(2).expect("group should have 3 rucksacks");
+            for b in line.bytes() {
+                if items.contains(&b) {
+                    sum += item_priority(b);
+                    continue 'group_loop;
+                }
+            }
+        }
+
+        return sum.into();
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut rucksacks = String::new();
+
+        // Rucksacks come in groups of 3, each with one badge for part B.
+        for _ in 0..size.div_ceil(3).max(1) {
+            let badge = *rng.choose(ITEMS);
+            // Every other item is kept out of one of the rucksacks, so the
+            // badge is the only item all 3 have.
+            let left_out: Vec<usize> = ITEMS.iter().map(|_| rng.index(3)).collect();
+
+            for i in 0..3 {
+                let allowed: Vec<u8> = ITEMS
+                    .iter()
+                    .zip(&left_out)
+                    .filter(|&(&item, &j)| j != i && item != badge)
+                    .map(|(&item, _)| item)
+                    .collect();
+
+                // The shared item is the only one in both compartments for
+                // part A. It's sometimes the badge.
+                let shared = if rng.chance(0.2) {
+                    badge
+                } else {
+                    *rng.choose(&allowed)
+                };
+                let mut rest: Vec<u8> = allowed.into_iter().filter(|&b| b != shared).collect();
+                rng.shuffle(&mut rest);
+                let (left_items, right_items) = rest.split_at(rest.len() / 2);
+
+                let mut left = vec![shared];
+                let mut right = vec![shared];
+                if shared != badge {
+                    if rng.chance(0.5) {
+                        left.push(badge);
+                    } else {
+                        right.push(badge);
+                    }
+                }
+                let len = rng.range(2..=16) as usize;
+                while left.len() < len {
+                    left.push(*rng.choose(left_items));
+                }
+                while right.len() < len {
+                    right.push(*rng.choose(right_items));
+                }
+                rng.shuffle(&mut left);
+                rng.shuffle(&mut right);
+
+                left.extend(right);
+                left.push(b'\n');
+                rucksacks += &String::from_utf8(left).expect("items should be ASCII");
+            }
+        }
+
+        return Some(rucksacks);
+    }
+
+    fn validate(part: Part, raw: &str, rucksacks: &Vec<String>) -> Vec<ParseError> {
+        if part == Part::B && !rucksacks.len().is_multiple_of(3) {
+            return vec![end_of_input(raw, "a rucksack to finish the group of 3")];
+        }
+        return Vec::new();
+    }
+}
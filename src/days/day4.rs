@@ -0,0 +1,66 @@
+use crate::interval::Interval;
+use crate::parse::lines;
+use crate::rng::Rng;
+use crate::scan::template;
+use crate::{Answer, Context, ParseError, Solution};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    // Sections assigned to each pair of elves.
+    type Input = Vec<[Interval; 2]>;
+
+    fn parse(input: &str) -> Result<Vec<[Interval; 2]>, ParseError> {
+        lines(input)
+            .map(|line| {
+                let bounds: [&str; 4] = template(line, line.text, "{}-{},{}-{}")?;
+                let [start_1, end_1, start_2, end_2] =
+                    bounds.map(|s| line.parse::<u32>(s, "a section number"));
+
+                Ok([
+                    Interval::new(start_1?.into(), end_1?.into()),
+                    Interval::new(start_2?.into(), end_2?.into()),
+                ])
+            })
+            .collect()
+    }
+
+    fn part_a(pairs: &Vec<[Interval; 2]>, _: &Context) -> Answer {
+        let mut count = 0;
+
+        for [first, second] in pairs {
+            if first.covers(*second) || second.covers(*first) {
+                count += 1;
+            }
+        }
+
+        return count.into();
+    }
+
+    fn part_b(pairs: &Vec<[Interval; 2]>, _: &Context) -> Answer {
+        let mut count = 0;
+
+        for [first, second] in pairs {
+            if first.overlaps(*second) {
+                count += 1;
+            }
+        }
+
+        return count.into();
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let mut pairs = String::new();
+        for _ in 0..size {
+            let [first, second] = [(); 2].map(|_| {
+                let start = rng.range(1..=99);
+                Interval::new(start, rng.range(start..=99))
+            });
+            pairs += &format!(
+                "{}-{},{}-{}\n",
+                first.start, first.end, second.start, second.end
+            );
+        }
+        return Some(pairs);
+    }
+}
@@ -0,0 +1,251 @@
+use crate::parse::{end_of_input, lines, Line};
+use crate::rng::Rng;
+use crate::scan::template;
+use crate::{Answer, Context, ParseError, Part, Solution};
+
+pub struct Procedure {
+    amt: u32,
+    src: u32,
+    dst: u32,
+}
+
+pub struct Input {
+    // Initial stacks, from bottom to top.
+    stacks: Vec<String>,
+    procedures: Vec<Procedure>,
+}
+
+fn tops(stacks: &[String]) -> Answer {
+    stacks
+        .iter()
+        .filter_map(|s| s.chars().last())
+        .collect::<String>()
+        .into()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Input;
+
+    fn parse(input: &str) -> Result<Input, ParseError> {
+        let mut lines = lines(input);
+        let mut stack_lines = Vec::new();
+
+        // Read in lines containing initial stack arrangement, up to the line
+        // numbering the stacks.
+        let numbers = loop {
+            let line = lines
+                .next()
+                .ok_or_else(|| end_of_input(input, "a line of stack numbers"))?;
+
+            if line.char_at(1, "a crate or stack number")? == '1' {
+                break line;
+            }
+
+            stack_lines.push(line);
+        };
+
+        stack_lines.reverse();
+
+        // Number of stacks is taken from the line of numbers, as the lines of
+        // crates can be any length: a line missing its trailing spaces is
+        // just as good.
+        let num_stacks = numbers.text.split_whitespace().count();
+
+        let mut stacks = vec![String::new(); num_stacks];
+        for line in stack_lines {
+            // Crate names are at index 1 of each stack's 4 chars.
+            for (j, maybe_crate) in line.text.chars().enumerate() {
+                if j % 4 != 1 || maybe_crate == ' ' {
+                    continue;
+                }
+                match stacks.get_mut(j / 4) {
+                    Some(stack) => stack.push(maybe_crate),
+                    None => {
+                        return Err(line.error_at_char(
+                            j,
+                            &format!("a crate in one of the {} stacks", num_stacks),
+                        ))
+                    }
+                }
+            }
+        }
+
+        // Skip blank separator line.
+        if let Some(line) = lines.next() {
+            if !line.text.is_empty() {
+                return Err(line.error(line.text, "a blank line"));
+            }
+        }
+
+        // Read through rearrangement procedure.
+        let procedures = lines
+            .map(|line| {
+                let [amt, src, dst] = template(line, line.text, "move {} from {} to {}")?;
+
+                let amt = line.parse(amt, "a number of crates")?;
+                let [src, dst] = [src, dst].map(|stack| match stack.parse() {
+                    Ok(n) if (1..=num_stacks as u32).contains(&n) => Ok(n),
+                    _ => {
+                        Err(line.error(stack, &format!("a stack number from 1 to {}", num_stacks)))
+                    }
+                });
+
+                Ok(Procedure {
+                    amt: amt,
+                    src: src?,
+                    dst: dst?,
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        return Ok(Input {
+            stacks: stacks,
+            procedures: procedures,
+        });
+    }
+
+    fn part_a(input: &Input, _: &Context) -> Answer {
+        let mut stacks = input.stacks.clone();
+
+        for procedure in &input.procedures {
+            for _ in 0..procedure.amt {
+                let src_stack = stacks
+                    .get_mut(procedure.src as usize - 1)
+                    .expect("procedure should refer to a valid source stack");
+                let cr = src_stack
+                    .pop()
+                    .expect("source stack should contain crate(s)");
+
+                let dst_stack = stacks
+                    .get_mut(procedure.dst as usize - 1)
+                    .expect("procedure should refer to a valid destination stack");
+                dst_stack.push(cr);
+            }
+        }
+
+        // Output tops of stacks.
+        return tops(&stacks);
+    }
+
+    fn part_b(input: &Input, _: &Context) -> Answer {
+        let mut stacks = input.stacks.clone();
+
+        for procedure in &input.procedures {
+            let src_stack = stacks
+                .get_mut(procedure.src as usize - 1)
+                .expect("procedure should refer to a valid source stack");
+            let cr = src_stack.split_off(src_stack.len() - procedure.amt as usize);
+            let dst_stack = stacks
+                .get_mut(procedure.dst as usize - 1)
+                .expect("procedure should refer to a valid destination stack");
+            dst_stack.push_str(cr.as_str());
+        }
+
+        // Output tops of stacks.
+        return tops(&stacks);
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        // Stacks are numbered with single digits, so there are at most 9.
+        let num_stacks = 9;
+        let mut stacks: Vec<Vec<u8>> = (0..num_stacks)
+            .map(|_| {
+                let height = rng.range(1..=8);
+                (0..height).map(|_| b'A' + rng.below(26) as u8).collect()
+            })
+            .collect();
+        let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+
+        // Every line of the drawing is padded out to the full width, as in
+        // the real inputs.
+        let mut input = String::new();
+        for row in (0..height).rev() {
+            let crates: Vec<String> = stacks
+                .iter()
+                .map(|stack| match stack.get(row) {
+                    Some(&c) => format!("[{}]", c as char),
+                    None => String::from("   "),
+                })
+                .collect();
+            input += &(crates.join(" ") + "\n");
+        }
+        let numbers: Vec<String> = (1..=num_stacks).map(|n| format!(" {} ", n)).collect();
+        input += &(numbers.join(" ") + "\n\n");
+
+        // Each move only takes as many crates as its source stack has.
+        for _ in 0..size {
+            let src = loop {
+                let src = rng.index(num_stacks);
+                if !stacks[src].is_empty() {
+                    break src;
+                }
+            };
+            let dst = (src + 1 + rng.index(num_stacks - 1)) % num_stacks;
+            let amt = rng.range(1..=stacks[src].len() as i64) as usize;
+
+            let rest = stacks[src].len() - amt;
+            let moved = stacks[src].split_off(rest);
+            stacks[dst].extend(moved);
+            input += &format!("move {} from {} to {}\n", amt, src + 1, dst + 1);
+        }
+
+        return Some(input);
+    }
+
+    fn validate(_: Part, raw: &str, input: &Input) -> Vec<ParseError> {
+        let mut problems = Vec::new();
+        let all_lines: Vec<Line> = lines(raw).collect();
+        let n = all_lines
+            .iter()
+            .position(|line| line.text.chars().nth(1) == Some('1'))
+            .expect("parsed input should have a line of stack numbers");
+
+        // Stacks should be numbered in order, each under its crates.
+        let numbers = all_lines[n];
+        for (i, number) in numbers.text.split_whitespace().enumerate() {
+            if number != (i + 1).to_string() || numbers.column(number) != 4 * i + 2 {
+                problems.push(numbers.error(number, &format!("stack number {}", i + 1)));
+            }
+        }
+
+        // A crate with a gap under it would be read as if it had fallen.
+        for pair in all_lines[..n].windows(2) {
+            let (above, below) = (pair[0], pair[1]);
+            for (j, c) in above.text.chars().enumerate() {
+                if j % 4 == 1 && c != ' ' && below.text.chars().nth(j).unwrap_or(' ') == ' ' {
+                    problems.push(below.error_at_char(j, "a crate under the one above"));
+                }
+            }
+        }
+
+        // Each move should take at most as many crates as its source stack
+        // has at the time.
+        let mut heights: Vec<usize> = input.stacks.iter().map(String::len).collect();
+        let procedure_lines = all_lines.get(n + 2..).unwrap_or(&[]);
+        for (line, procedure) in procedure_lines.iter().zip(&input.procedures) {
+            let (src, dst) = (procedure.src as usize - 1, procedure.dst as usize - 1);
+            let mut amt = procedure.amt as usize;
+            if amt > heights[src] {
+                let [token, _, _] = template(*line, line.text, "move {} from {} to {}")
+                    .expect("procedure should have parsed");
+                problems.push(line.error(
+                    token,
+                    &format!(
+                        "a number of crates up to the {} in stack {}",
+                        heights[src],
+                        src + 1
+                    ),
+                ));
+                // Carry on as if the whole stack moved, to check the rest.
+                amt = heights[src];
+            }
+            heights[src] -= amt;
+            heights[dst] += amt;
+        }
+
+        problems.sort_by_key(|e| (e.line, e.column));
+        return problems;
+    }
+}
@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+use crate::parse::{end_of_input, lines};
+use crate::rng::Rng;
+use crate::{Answer, Context, ParseError, Part, Solution};
+
+fn inc(map: &mut HashMap<u8, u8>, val: u8) {
+    match map.get(&val) {
+        Some(v) => {
+            map.insert(val, v + 1);
+        }
+        None => {
+            map.insert(val, 1);
+        }
+    }
+}
+
+fn dec(map: &mut HashMap<u8, u8>, val: u8) {
+    match map.get(&val) {
+        Some(1) => {
+            map.remove(&val);
+        }
+        Some(v) => {
+            map.insert(val, v - 1);
+        }
+        None => {
+            panic!("val that is being decremented should be in map");
+        }
+    }
+}
+
+// Returns the number of characters processed before the first window of `size`
+// distinct characters is complete, or None if there is no such window.
+fn find_marker(line: &str, size: usize) -> Option<usize> {
+    let mut map = HashMap::new();
+    for b in line.bytes().take(size) {
+        inc(&mut map, b);
+    }
+
+    if map.len() == size {
+        return Some(size);
+    }
+
+    let bytes = line.as_bytes();
+    for i in size..bytes.len() {
+        inc(&mut map, bytes[i]);
+        dec(&mut map, bytes[i - size]);
+
+        if map.len() == size {
+            return Some(i + 1);
+        }
+    }
+
+    return None;
+}
+
+// Length of the marker each part looks for.
+fn marker_size(part: Part) -> usize {
+    match part {
+        Part::A => 4,
+        Part::B => 14,
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    // The datastream buffer.
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        match input.lines().next() {
+            Some(line) => Ok(line.to_string()),
+            None => Err(end_of_input(input, "a datastream")),
+        }
+    }
+
+    fn part_a(line: &String, _: &Context) -> Answer {
+        find_marker(line, marker_size(Part::A))
+            .expect("datastream should contain a marker")
+            .into()
+    }
+
+    fn part_b(line: &String, _: &Context) -> Answer {
+        find_marker(line, marker_size(Part::B))
+            .expect("datastream should contain a marker")
+            .into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let letters = b"abcdefghijklmnopqrstuvwxyz";
+
+        // About size * 100 characters that mostly repeat recent ones, so that
+        // markers are rare, then 14 different letters so that both parts find
+        // one.
+        let mut stream = Vec::new();
+        for i in 0..size * 100 {
+            if i > 0 && rng.chance(0.9) {
+                let back = rng.range(1..=6.min(i as i64)) as usize;
+                stream.push(stream[i - back]);
+            } else {
+                stream.push(*rng.choose(letters));
+            }
+        }
+        let mut marker = letters.to_vec();
+        rng.shuffle(&mut marker);
+        stream.extend(&marker[..14]);
+        for _ in 0..100 {
+            stream.push(*rng.choose(letters));
+        }
+
+        stream.push(b'\n');
+        return Some(String::from_utf8(stream).expect("stream should be ASCII"));
+    }
+
+    fn validate(part: Part, raw: &str, line: &String) -> Vec<ParseError> {
+        let size = marker_size(part);
+        if find_marker(line, size).is_none() {
+            let line = lines(raw).next().expect("parsed input should have a line");
+            return vec![line.error_at_end(&format!("a marker of {} different characters", size))];
+        }
+        return Vec::new();
+    }
+}
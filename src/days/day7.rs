@@ -0,0 +1,310 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::rc::Rc;
+use std::vec::Vec;
+
+use crate::parse::lines;
+use crate::rng::Rng;
+use crate::scan::template;
+use crate::{Answer, Context, Param, ParseError, Part, Solution};
+
+pub struct Filesystem {
+    entities: Vec<Rc<RefCell<Entity>>>,
+}
+
+impl Filesystem {
+    fn new() -> Filesystem {
+        let root = Entity::Dir(Directory::new(0));
+        return Filesystem {
+            entities: vec![Rc::new(RefCell::new(root))],
+        };
+    }
+
+    fn add_entity(&mut self, entity: Rc<RefCell<Entity>>) -> usize {
+        let index = self.entities.len();
+        self.entities.push(Rc::clone(&entity));
+        return index;
+    }
+
+    fn get(&self, index: usize) -> Rc<RefCell<Entity>> {
+        Rc::clone(&self.entities[index])
+    }
+
+    fn used(&self) -> u64 {
+        self.get(0).borrow().as_dir().borrow().size(self)
+    }
+
+    fn dirs(&self) -> impl Iterator<Item = Rc<RefCell<Directory>>> + '_ {
+        return self
+            .entities
+            .iter()
+            .filter(|e| matches!(&*e.borrow(), Entity::Dir(_)))
+            .map(|e| Rc::clone(&e.borrow().as_dir()));
+    }
+}
+
+enum Entity {
+    File(u64),
+    Dir(Rc<RefCell<Directory>>),
+}
+
+impl Entity {
+    fn as_dir(&self) -> Rc<RefCell<Directory>> {
+        match self {
+            Entity::File(_) => panic!("entity should be Dir"),
+            Entity::Dir(d) => Rc::clone(d),
+        }
+    }
+}
+
+struct Directory {
+    parent: usize,
+    contents: HashMap<String, usize>,
+}
+
+impl Directory {
+    fn new(parent: usize) -> Rc<RefCell<Directory>> {
+        return Rc::new(RefCell::new(Directory {
+            parent: parent,
+            contents: HashMap::new(),
+        }));
+    }
+
+    fn insert(&mut self, name: String, index: usize) {
+        self.contents.insert(name, index);
+    }
+
+    fn get(&self, name: String) -> Option<usize> {
+        self.contents.get(&name).copied()
+    }
+
+    fn size(&self, fs: &Filesystem) -> u64 {
+        self.contents.values().fold(0, |acc, i| {
+            return acc
+                + match &*fs.get(*i).borrow() {
+                    Entity::File(s) => *s,
+                    Entity::Dir(d) => d.borrow().size(fs),
+                };
+        })
+    }
+}
+
+// Size of the disk, and how much of it has to be free for the update.
+const DISK_SIZE: u64 = 70000000;
+const UPDATE_SIZE: u64 = 30000000;
+
+// A directory in a made-up filesystem, for the generator.
+struct GeneratedDir {
+    name: String,
+    files: Vec<(String, u64)>,
+    children: Vec<usize>,
+}
+
+// Writes the terminal session that explores a made-up directory and
+// everything in it.
+fn write_session(dirs: &[GeneratedDir], dir: usize, rng: &mut Rng, session: &mut String) {
+    let mut listing: Vec<String> = dirs[dir]
+        .children
+        .iter()
+        .map(|&child| format!("dir {}", dirs[child].name))
+        .chain(
+            dirs[dir]
+                .files
+                .iter()
+                .map(|(name, size)| format!("{} {}", size, name)),
+        )
+        .collect();
+    rng.shuffle(&mut listing);
+
+    *session += "$ ls\n";
+    for entry in listing {
+        *session += &(entry + "\n");
+    }
+    for &child in &dirs[dir].children {
+        *session += &format!("$ cd {}\n", dirs[child].name);
+        write_session(dirs, child, rng, session);
+        *session += "$ cd ..\n";
+    }
+}
+
+// A made-up file or directory name, different from the ones already taken.
+// Only files get extensions.
+fn new_name(rng: &mut Rng, taken: &mut HashSet<String>, file: bool) -> String {
+    loop {
+        let len = rng.range(1..=8);
+        let mut name: String = (0..len)
+            .map(|_| (b'a' + rng.below(26) as u8) as char)
+            .collect();
+        if file && rng.chance(0.3) {
+            name += *rng.choose(&[".txt", ".dat", ".log", ".lst"]);
+        }
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    }
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Filesystem;
+
+    const PARAMS: &'static [Param] = &[
+        Param {
+            name: "threshold",
+            default: 100000,
+            min: 0,
+            max: i64::MAX,
+            description: "Largest directory size counted in part A",
+        },
+    ];
+
+    fn parse(input: &str) -> Result<Filesystem, ParseError> {
+        let mut fs = Filesystem::new();
+        let mut cd = 0;
+
+        for line in lines(input) {
+            if line.text.trim_start().starts_with('$') {
+                // Parse out shell command.
+                let [cmd] = template(line, line.text, "${}")?;
+                match cmd.split_whitespace().next() {
+                    Some("cd") => {
+                        // The target is whatever follows "cd", which is
+                        // already known to be a whole word.
+                        let [target] = template(line, cmd, "cd{}")?;
+                        if target == "/" {
+                            // Set current directory to root FS directory.
+                            cd = 0;
+                        } else if target == ".." {
+                            // Go up one level via current directory's parent.
+                            let dir = fs.get(cd).borrow().as_dir();
+                            cd = dir.borrow().parent;
+                        } else {
+                            // Go down one level by getting named directory from contents.
+                            let dir = fs.get(cd).borrow().as_dir();
+                            cd = dir
+                                .borrow()
+                                .get(target.to_string())
+                                .filter(|&i| matches!(&*fs.get(i).borrow(), Entity::Dir(_)))
+                                .ok_or_else(|| line.error(target, "a listed directory"))?;
+                        }
+                    }
+                    // Its output will go to the else case below.
+                    Some("ls") => {
+                        let [] = template(line, cmd, "ls")?;
+                    }
+                    _ => return Err(line.error(cmd, "a cd or ls command")),
+                }
+            } else {
+                // Add entry from ls into current directory.
+                let dir = fs.get(cd).borrow_mut().as_dir();
+
+                let [meta, name] = template(line, line.text, "{} {}")?;
+                let name = name.to_string();
+
+                if meta == "dir" {
+                    let ent = Rc::new(RefCell::new(Entity::Dir(Directory::new(cd))));
+                    let new_dir = fs.add_entity(ent);
+                    dir.borrow_mut().insert(name, new_dir);
+                } else {
+                    let ent = Rc::new(RefCell::new(Entity::File(
+                        line.parse(meta, "\"dir\" or a file size")?,
+                    )));
+                    let file = fs.add_entity(ent);
+                    dir.borrow_mut().insert(name, file);
+                }
+            }
+        }
+
+        return Ok(fs);
+    }
+
+    fn part_a(fs: &Filesystem, ctx: &Context) -> Answer {
+        let threshold = ctx.param("threshold") as u64;
+        let size_sum: u64 = fs
+            .dirs()
+            .map(|d| d.borrow().size(fs))
+            .filter(|&s| s <= threshold)
+            .sum();
+        return size_sum.into();
+    }
+
+    fn part_b(fs: &Filesystem, _: &Context) -> Answer {
+        let cur_free = DISK_SIZE - fs.used();
+        let to_delete = UPDATE_SIZE - cur_free;
+
+        let size_min: u64 = fs
+            .dirs()
+            .map(|d| d.borrow().size(fs))
+            .filter(|&s| s >= to_delete)
+            .min()
+            .expect("should have at least one directory eligible for removal");
+        return size_min.into();
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        // One directory per size, each inside a random earlier one, and each
+        // holding a few files.
+        let mut dirs = vec![GeneratedDir {
+            name: String::from("/"),
+            files: Vec::new(),
+            children: Vec::new(),
+        }];
+        let mut taken = vec![HashSet::new()];
+        for i in 1..size.max(1) {
+            let parent = rng.index(i);
+            let name = new_name(rng, &mut taken[parent], false);
+            dirs[parent].children.push(i);
+            dirs.push(GeneratedDir {
+                name: name,
+                files: Vec::new(),
+                children: Vec::new(),
+            });
+            taken.push(HashSet::new());
+        }
+
+        let mut weights = Vec::new();
+        for (i, dir) in dirs.iter_mut().enumerate() {
+            let min_files = if i == 0 { 1 } else { 0 };
+            for _ in 0..rng.range(min_files..=4) {
+                let weight = rng.range(1..=1000) as u64;
+                dir.files.push((new_name(rng, &mut taken[i], true), weight));
+                weights.push(weight);
+            }
+        }
+
+        // Part B needs between 40000000 and 70000000 used out of 70000000, so
+        // scale the files to fill that much.
+        let total = rng.range(45000000..=65000000) as u64;
+        let weight_sum: u64 = weights.iter().sum();
+        for dir in &mut dirs {
+            for (_, size) in &mut dir.files {
+                *size = (*size * total / weight_sum).max(1);
+            }
+        }
+
+        let mut session = String::from("$ cd /\n");
+        write_session(&dirs, 0, rng, &mut session);
+        return Some(session);
+    }
+
+    fn validate(part: Part, raw: &str, fs: &Filesystem) -> Vec<ParseError> {
+        // Part B needs the files to fit on the disk, but not leave room for
+        // the update already.
+        let used = fs.used();
+        if part == Part::B && !(DISK_SIZE - UPDATE_SIZE..=DISK_SIZE).contains(&used) {
+            return vec![ParseError::new(
+                raw.lines().count() + 1,
+                1,
+                &format!(
+                    "files adding up to between {} and {}",
+                    DISK_SIZE - UPDATE_SIZE,
+                    DISK_SIZE
+                ),
+                &format!("{} in all", used),
+            )];
+        }
+        return Vec::new();
+    }
+}
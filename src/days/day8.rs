@@ -0,0 +1,146 @@
+use crate::grid::Grid;
+use crate::parse::{end_of_input, lines};
+use crate::rng::Rng;
+use crate::{Answer, Context, ParseError, Solution};
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    // Map of tree heights.
+    type Input = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Grid<u32>, ParseError> {
+        let map = Grid::parse(input, |line, i, c| {
+            c.to_digit(10)
+                .ok_or_else(|| line.error_at_char(i, "a tree height"))
+        })?;
+
+        // There's no edge of the forest to look from without any trees.
+        if let Some(first) = lines(input).next().filter(|_| map.width() == 0) {
+            return Err(first.error_at_end("a tree height"));
+        }
+        if map.height() == 0 {
+            return Err(end_of_input(input, "a row of tree heights"));
+        }
+
+        return Ok(map);
+    }
+
+    fn part_a(map: &Grid<u32>, _: &Context) -> Answer {
+        // Scan map and mark visible trees.
+        let rows = map.height();
+        let cols = map.width();
+
+        let mut vis_map = Grid::new(rows, cols, false);
+
+        for r in 0..rows {
+            let mut max = None;
+            for c in 0..cols {
+                if max.is_none_or(|v| map[(r, c)] > v) {
+                    vis_map[(r, c)] = true;
+                    max = Some(map[(r, c)]);
+                }
+            }
+            max = None;
+            for c in (0..cols).rev() {
+                if max.is_none_or(|v| map[(r, c)] > v) {
+                    vis_map[(r, c)] = true;
+                    max = Some(map[(r, c)]);
+                }
+            }
+        }
+
+        for c in 0..cols {
+            let mut max = None;
+            for r in 0..rows {
+                if max.is_none_or(|v| map[(r, c)] > v) {
+                    vis_map[(r, c)] = true;
+                    max = Some(map[(r, c)]);
+                }
+            }
+            max = None;
+            for r in (0..rows).rev() {
+                if max.is_none_or(|v| map[(r, c)] > v) {
+                    vis_map[(r, c)] = true;
+                    max = Some(map[(r, c)]);
+                }
+            }
+        }
+
+        let sum = vis_map.iter().filter(|&(_, v)| *v).count();
+        return sum.into();
+    }
+
+    fn part_b(map: &Grid<u32>, _: &Context) -> Answer {
+        let rows = map.height();
+        let cols = map.width();
+
+        let mut max = 0;
+
+        for r in 1..rows - 1 {
+            for c in 1..cols - 1 {
+                let height = map[(r, c)];
+                let mut score = 1;
+
+                let mut dist = 1;
+                'east_loop: for cc in c + 1..cols - 1 {
+                    if map[(r, cc)] >= height {
+                        break 'east_loop;
+                    }
+                    dist += 1;
+                }
+                score *= dist;
+
+                dist = 1;
+                'west_loop: for cc in (1..c).rev() {
+                    if map[(r, cc)] >= height {
+                        break 'west_loop;
+                    }
+                    dist += 1;
+                }
+                score *= dist;
+
+                dist = 1;
+                'south_loop: for rr in r + 1..rows - 1 {
+                    if map[(rr, c)] >= height {
+                        break 'south_loop;
+                    }
+                    dist += 1;
+                }
+                score *= dist;
+
+                dist = 1;
+                'north_loop: for rr in (1..r).rev() {
+                    if map[(rr, c)] >= height {
+                        break 'north_loop;
+                    }
+                    dist += 1;
+                }
+                score *= dist;
+
+                if score > max {
+                    max = score;
+                }
+            }
+        }
+
+        return max.into();
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        // A square forest, at least 2 trees across.
+        let side = size.max(2);
+        let forest = (0..side)
+            .map(|_| {
+                let row: String = (0..side)
+                    .map(|_| {
+                        char::from_digit(rng.below(10) as u32, 10)
+                            .expect("height should be a digit")
+                    })
+                    .collect();
+                row + "\n"
+            })
+            .collect();
+        return Some(forest);
+    }
+}
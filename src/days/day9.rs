@@ -0,0 +1,120 @@
+use std::collections::HashSet;
+
+use crate::frames::Frame;
+use crate::grid::Grid;
+use crate::parse::{lines, Line};
+use crate::point::{Direction, Point2};
+use crate::rng::Rng;
+use crate::scan::template;
+use crate::{Answer, Context, ParseError, Solution};
+
+pub struct Motion {
+    dir: Direction,
+    steps: u32,
+}
+
+impl Motion {
+    fn from(line: Line) -> Result<Motion, ParseError> {
+        let [dir, steps] = template(line, line.text, "{} {}")?;
+        let dir = dir
+            .parse()
+            .map_err(|_| line.error(dir, "a direction L, R, U or D"))?;
+        let steps = line.parse(steps, "a number of steps")?;
+
+        return Ok(Motion {
+            dir: dir,
+            steps: steps,
+        });
+    }
+}
+
+// Draws the rope over the positions its tail has visited, like the puzzle
+// does, in a box just big enough for both.
+fn frame(rope: &[Point2], positions: &HashSet<Point2>) -> Frame {
+    let points = || rope.iter().chain(positions.iter());
+    let top = points().map(|p| p.y).min().unwrap_or(0);
+    let left = points().map(|p| p.x).min().unwrap_or(0);
+    let bottom = points().map(|p| p.y).max().unwrap_or(0);
+    let right = points().map(|p| p.x).max().unwrap_or(0);
+
+    let mut cells = Grid::new(
+        (bottom - top) as usize + 1,
+        (right - left) as usize + 1,
+        b'.',
+    );
+    let cell = |p: &Point2| ((p.y - top) as usize, (p.x - left) as usize);
+    for p in positions {
+        cells[cell(p)] = b'#';
+    }
+    // Knots nearer the head are drawn over the ones behind them.
+    for (i, knot) in rope.iter().enumerate().rev() {
+        cells[cell(knot)] = match i {
+            0 => b'H',
+            _ if rope.len() == 2 => b'T',
+            _ => b'0' + i.min(9) as u8,
+        };
+    }
+
+    return Frame::new(top, left, cells);
+}
+
+// Moves a rope of the given number of knots through the motions and returns
+// the number of positions visited by the tail.
+fn simulate(motions: &[Motion], knots: usize, ctx: &Context) -> usize {
+    let mut positions = HashSet::new();
+    let mut rope = vec![Point2::ORIGIN; knots];
+    ctx.frame(|| frame(&rope, &positions));
+
+    for m in motions {
+        for _ in 0..m.steps {
+            rope[0] += m.dir.delta();
+
+            // A knot that is no longer touching the one in front of it moves
+            // one step towards it, diagonally if they aren't in line.
+            for i in 1..knots {
+                let head = rope[i - 1];
+                let tail = &mut rope[i];
+
+                if head.chebyshev(*tail) > 1 {
+                    *tail += (head - *tail).signum();
+                }
+            }
+
+            positions.insert(rope[knots - 1]);
+            ctx.frame(|| frame(&rope, &positions));
+        }
+    }
+
+    return positions.len();
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Motion>;
+
+    fn parse(input: &str) -> Result<Vec<Motion>, ParseError> {
+        lines(input).map(Motion::from).collect()
+    }
+
+    fn part_a(motions: &Vec<Motion>, ctx: &Context) -> Answer {
+        simulate(motions, 2, ctx).into()
+    }
+
+    fn part_b(motions: &Vec<Motion>, ctx: &Context) -> Answer {
+        simulate(motions, 10, ctx).into()
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let motions = (0..size)
+            .map(|_| {
+                format!(
+                    "{} {}\n",
+                    rng.choose(&['U', 'D', 'L', 'R']),
+                    rng.range(1..=20)
+                )
+            })
+            .collect();
+        return Some(motions);
+    }
+}
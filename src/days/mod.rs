@@ -0,0 +1,217 @@
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+use std::io::Read;
+
+use crate::cache::{self, Key};
+use crate::config::{self, InputKind};
+use crate::rng::Rng;
+use crate::{
+    check, solve_reference, solve_timed, Answer, Context, InputError, Param, ParseError, Part,
+    Puzzle, Solution, Timing,
+};
+
+type SolveFn = fn(Part, &str, &Context) -> Result<(Answer, Timing), ParseError>;
+type ReferenceFn = fn(Part, &str, &Context) -> Result<Option<Answer>, ParseError>;
+type GenerateFn = fn(&mut Rng, usize) -> Option<String>;
+type CheckFn = fn(Part, &str) -> Vec<ParseError>;
+
+// Type-erased entry for a day's solution, so that days can be picked at
+// runtime.
+pub struct Day {
+    pub day: u8,
+    pub parts: &'static [Part],
+    pub params: &'static [Param],
+    // Source code of the day's module, so that answers worked out by an older
+    // version of it can be told apart.
+    pub source: &'static str,
+    solve: SolveFn,
+    reference: ReferenceFn,
+    generate: GenerateFn,
+    check: CheckFn,
+}
+
+impl Day {
+    pub fn puzzles(&self) -> impl Iterator<Item = Puzzle> + '_ {
+        self.parts.iter().map(|&part| Puzzle {
+            day: self.day,
+            part: part,
+        })
+    }
+
+    // Builds the context for running this day, with (name, value) pairs
+    // overriding the defaults of its parameters.
+    pub fn context(&self, params: &[(String, i64)]) -> Result<Context, String> {
+        Context::new(self.params, params).map_err(|e| format!("day {}: {}", self.day, e))
+    }
+
+    // Parameter values for running this day on a kind of input: those set in
+    // the config, then the (name, value) pairs given, which override them.
+    pub fn configured_params(
+        &self,
+        kind: InputKind,
+        overrides: &[(String, i64)],
+    ) -> Vec<(String, i64)> {
+        let mut params = config::get().params(self.day, kind);
+        params.extend(overrides.iter().cloned());
+        return params;
+    }
+
+    pub fn solve(&self, part: Part, input: &str, ctx: &Context) -> Result<Answer, ParseError> {
+        self.solve_timed(part, input, ctx).map(|(answer, _)| answer)
+    }
+
+    pub fn solve_timed(
+        &self,
+        part: Part,
+        input: &str,
+        ctx: &Context,
+    ) -> Result<(Answer, Timing), ParseError> {
+        (self.solve)(part, input, ctx).map_err(|e| e.in_day(self.day))
+    }
+
+    // Reads the whole input from a reader (a file, stdin, or a byte slice)
+    // and solves it. Reading isn't included in the timing.
+    pub fn solve_from(
+        &self,
+        part: Part,
+        reader: &mut dyn Read,
+        ctx: &Context,
+    ) -> Result<(Answer, Timing), InputError> {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        return Ok(self.solve_timed(part, &input, ctx)?);
+    }
+
+    // Solves a part with the given function, unless use_cache is set and the
+    // answer for this input and these (name, value) parameter overrides is in
+    // the cache already. Solved answers are added to the cache if it's in use.
+    pub fn solve_cached<E>(
+        &self,
+        part: Part,
+        input: &str,
+        params: &[(String, i64)],
+        use_cache: bool,
+        solve: impl FnOnce() -> Result<(Answer, Timing), E>,
+    ) -> Result<(Answer, Timing), E> {
+        let key = use_cache.then(|| Key::new(self, part, input, params));
+        if let Some(answer) = key.as_ref().and_then(cache::get) {
+            let timing = Timing {
+                cached: true,
+                ..Timing::default()
+            };
+            return Ok((answer, timing));
+        }
+
+        let solved = solve()?;
+        if let Some(key) = &key {
+            // Not being able to cache the answer only means solving it again
+            // next time.
+            if let Err(e) = cache::put(key, &solved.0) {
+                eprintln!("warning: could not cache {} answer: {}", key.puzzle, e);
+            }
+        }
+        return Ok(solved);
+    }
+
+    // Solves a part with the day's slow reference solution instead. None if
+    // the part has no reference solution.
+    pub fn solve_reference(
+        &self,
+        part: Part,
+        input: &str,
+        ctx: &Context,
+    ) -> Result<Option<Answer>, ParseError> {
+        (self.reference)(part, input, ctx).map_err(|e| e.in_day(self.day))
+    }
+
+    // Makes up a random input of about the given size. The same seed always
+    // gives the same input. None if the day has no generator.
+    pub fn generate(&self, size: usize, seed: u64) -> Option<String> {
+        (self.generate)(&mut Rng::new(seed), size)
+    }
+
+    // Every problem with an input that would trip up solving a part, found
+    // without solving it.
+    pub fn check(&self, part: Part, input: &str) -> Vec<ParseError> {
+        (self.check)(part, input)
+            .into_iter()
+            .map(|e| e.in_day(self.day))
+            .collect()
+    }
+}
+
+macro_rules! day {
+    ($day:literal, $solution:ty) => {
+        Day {
+            day: $day,
+            parts: <$solution as Solution>::PARTS,
+            params: <$solution as Solution>::PARAMS,
+            source: include_str!(concat!("day", $day, ".rs")),
+            solve: solve_timed::<$solution>,
+            reference: solve_reference::<$solution>,
+            generate: <$solution as Solution>::generate,
+            check: check::<$solution>,
+        }
+    };
+}
+
+pub static DAYS: [Day; 25] = [
+    day!(1, day1::Day1),
+    day!(2, day2::Day2),
+    day!(3, day3::Day3),
+    day!(4, day4::Day4),
+    day!(5, day5::Day5),
+    day!(6, day6::Day6),
+    day!(7, day7::Day7),
+    day!(8, day8::Day8),
+    day!(9, day9::Day9),
+    day!(10, day10::Day10),
+    day!(11, day11::Day11),
+    day!(12, day12::Day12),
+    day!(13, day13::Day13),
+    day!(14, day14::Day14),
+    day!(15, day15::Day15),
+    day!(16, day16::Day16),
+    day!(17, day17::Day17),
+    day!(18, day18::Day18),
+    day!(19, day19::Day19),
+    day!(20, day20::Day20),
+    day!(21, day21::Day21),
+    day!(22, day22::Day22),
+    day!(23, day23::Day23),
+    day!(24, day24::Day24),
+    day!(25, day25::Day25),
+];
+
+pub fn get(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+// Every puzzle that has a solution, in order.
+pub fn puzzles() -> impl Iterator<Item = Puzzle> {
+    DAYS.iter().flat_map(|d| d.puzzles())
+}
@@ -0,0 +1,212 @@
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::days::Day;
+use crate::{panic_message, Answer, Context, ParseError, Part};
+
+// What running one solution on an input came to. A panic is kept as an
+// outcome rather than taking everything down, since a solution tripping over
+// an input is just the sort of thing being looked for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Answer(Answer),
+    Panic(String),
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Answer(answer) => write!(f, "{}", answer),
+            Outcome::Panic(message) => write!(f, "panicked: {}", message),
+        }
+    }
+}
+
+// The real and reference solutions' outcomes on the same input.
+#[derive(Clone, Debug)]
+pub struct Comparison {
+    pub solution: Outcome,
+    pub reference: Outcome,
+}
+
+impl Comparison {
+    pub fn agrees(&self) -> bool {
+        self.solution == self.reference
+    }
+}
+
+// A generated input the two solutions disagree on.
+#[derive(Clone, Debug)]
+pub struct Mismatch {
+    pub seed: u64,
+    pub size: usize,
+    // Number of lines in the input as generated.
+    pub generated_lines: usize,
+    // The input shrunk down as far as it would go while still showing the
+    // disagreement, and the outcomes on that.
+    pub input: String,
+    pub comparison: Comparison,
+}
+
+// How checking a puzzle went.
+#[derive(Clone, Debug)]
+pub enum Report {
+    // The two solutions agreed on this many inputs.
+    Agreed(usize),
+    NoReference,
+    NoGenerator,
+    // The generator made an input that doesn't parse.
+    BadInput {
+        seed: u64,
+        size: usize,
+        error: ParseError,
+    },
+    Mismatch(Box<Mismatch>),
+}
+
+fn catch(solve: impl FnOnce() -> Answer) -> Outcome {
+    match panic::catch_unwind(AssertUnwindSafe(solve)) {
+        Ok(answer) => Outcome::Answer(answer),
+        Err(payload) => Outcome::Panic(panic_message(&*payload)),
+    }
+}
+
+// Runs both solutions of a part on an input. None if the part has no
+// reference solution.
+pub fn compare(
+    day: &Day,
+    part: Part,
+    input: &str,
+    ctx: &Context,
+) -> Result<Option<Comparison>, ParseError> {
+    // Parsing returns an error rather than panicking on a bad input, so any
+    // panic here comes from solving.
+    let reference =
+        match panic::catch_unwind(AssertUnwindSafe(|| day.solve_reference(part, input, ctx))) {
+            Ok(Ok(Some(answer))) => Outcome::Answer(answer),
+            Ok(Ok(None)) => return Ok(None),
+            Ok(Err(e)) => return Err(e),
+            Err(payload) => Outcome::Panic(panic_message(&*payload)),
+        };
+    let solution = catch(|| {
+        day.solve(part, input, ctx)
+            .expect("input should parse, as it did for the reference")
+    });
+
+    return Ok(Some(Comparison {
+        solution: solution,
+        reference: reference,
+    }));
+}
+
+// Shrinks an input for as long as it stays interesting, by taking out runs of
+// lines: first halves, then quarters and so on down to single lines, going
+// back up a size whenever something could be taken out (delta debugging,
+// more or less).
+pub fn minimise(input: &str, interesting: impl Fn(&str) -> bool) -> String {
+    let join = |lines: &[&str]| -> String { lines.iter().map(|l| format!("{}\n", l)).collect() };
+    let mut lines: Vec<&str> = input.lines().collect();
+    let mut chunk = (lines.len() / 2).max(1);
+
+    loop {
+        let mut removed = false;
+        let mut i = 0;
+        while i < lines.len() {
+            let end = (i + chunk).min(lines.len());
+            let candidate: Vec<&str> = [&lines[..i], &lines[end..]].concat();
+            if interesting(&join(&candidate)) {
+                lines = candidate;
+                removed = true;
+            } else {
+                i = end;
+            }
+        }
+
+        if !removed {
+            if chunk == 1 {
+                break;
+            }
+            chunk /= 2;
+        }
+    }
+
+    return join(&lines);
+}
+
+// Checks a part against its reference solution on generated inputs, one for
+// each of a number of runs, with seeds counting up from the given one and
+// sizes growing from 1 to the given maximum. Stops at the first disagreement
+// and shrinks the input it happened on.
+pub fn check(
+    day: &Day,
+    part: Part,
+    ctx: &Context,
+    runs: usize,
+    max_size: usize,
+    first_seed: u64,
+) -> Report {
+    // Panics are expected along the way, so keep the default hook from
+    // printing each one while checking.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let report = check_quietly(day, part, ctx, runs, max_size, first_seed);
+    panic::set_hook(hook);
+
+    return report;
+}
+
+fn check_quietly(
+    day: &Day,
+    part: Part,
+    ctx: &Context,
+    runs: usize,
+    max_size: usize,
+    first_seed: u64,
+) -> Report {
+    for run in 0..runs {
+        let seed = first_seed.wrapping_add(run as u64);
+        let size = 1 + run * max_size / runs.max(1);
+        let Some(input) = day.generate(size, seed) else {
+            return Report::NoGenerator;
+        };
+        let comparison = match compare(day, part, &input, ctx) {
+            Ok(Some(comparison)) => comparison,
+            Ok(None) => return Report::NoReference,
+            Err(e) => {
+                return Report::BadInput {
+                    seed: seed,
+                    size: size,
+                    error: e,
+                }
+            }
+        };
+        if comparison.agrees() {
+            continue;
+        }
+
+        // Keep the shrunk input failing in the same way: the reference should
+        // still either answer or panic, as it did on the original.
+        let reference_panicked = matches!(comparison.reference, Outcome::Panic(_));
+        let interesting = |candidate: &str| match compare(day, part, candidate, ctx) {
+            Ok(Some(c)) => {
+                !c.agrees() && matches!(c.reference, Outcome::Panic(_)) == reference_panicked
+            }
+            _ => false,
+        };
+        let shrunk = minimise(&input, interesting);
+        let comparison = compare(day, part, &shrunk, ctx)
+            .ok()
+            .flatten()
+            .expect("shrunk input should still be comparable");
+
+        return Report::Mismatch(Box::new(Mismatch {
+            seed: seed,
+            size: size,
+            generated_lines: input.lines().count(),
+            input: shrunk,
+            comparison: comparison,
+        }));
+    }
+
+    return Report::Agreed(runs);
+}
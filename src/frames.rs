@@ -0,0 +1,311 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::gif;
+use crate::grid::Grid;
+
+// How long each frame of a GIF is shown, in hundredths of a second.
+const GIF_DELAY: u16 = 5;
+
+// Colours for the characters that the simulations draw with. Anything else is
+// drawn black.
+const PALETTE: [(&[u8], [u8; 3]); 11] = [
+    // Outside the frame.
+    (b" ", [255, 255, 255]),
+    (b".", [240, 240, 240]),
+    (b"#", [60, 60, 60]),
+    // Sand, and where it comes from.
+    (b"o", [214, 180, 90]),
+    (b"+", [200, 40, 40]),
+    // Rope knots.
+    (b"H", [200, 0, 0]),
+    (b"T", [0, 100, 200]),
+    (b"123456789", [230, 120, 0]),
+    // Blizzards, and the expedition.
+    (b"<>^v", [80, 160, 230]),
+    (b"E", [0, 160, 60]),
+    (b"", [0, 0, 0]),
+];
+
+// One picture of a simulation, drawn as ASCII. Its top-left cell is at (top,
+// left) in the simulation's own coordinates, so that frames of different
+// sizes can be lined up.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    pub top: i64,
+    pub left: i64,
+    pub cells: Grid<u8>,
+}
+
+impl Frame {
+    pub fn new(top: i64, left: i64, cells: Grid<u8>) -> Frame {
+        Frame {
+            top: top,
+            left: left,
+            cells: cells,
+        }
+    }
+
+    fn bottom(&self) -> i64 {
+        self.top + self.cells.height() as i64
+    }
+
+    fn right(&self) -> i64 {
+        self.left + self.cells.width() as i64
+    }
+
+    // Part of the frame inside the crop, which may be empty.
+    fn crop(&self, crop: Crop) -> Frame {
+        let top = self.top.max(crop.top);
+        let left = self.left.max(crop.left);
+        let bottom = self.bottom().min(crop.top + crop.height as i64).max(top);
+        let right = self.right().min(crop.left + crop.width as i64).max(left);
+
+        let cells = Grid::from_fn(
+            (bottom - top) as usize,
+            (right - left) as usize,
+            |(r, c)| {
+                self.cells[(
+                    (top - self.top) as usize + r,
+                    (left - self.left) as usize + c,
+                )]
+            },
+        );
+        return Frame::new(top, left, cells);
+    }
+}
+
+// A rectangle of a simulation to capture, in its own coordinates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Crop {
+    pub top: i64,
+    pub left: i64,
+    pub height: usize,
+    pub width: usize,
+}
+
+// Reads a crop written as "top,left,height,width".
+impl FromStr for Crop {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Crop, String> {
+        let parts: Vec<&str> = s.split(',').map(str::trim).collect();
+        let [top, left, height, width] = parts[..] else {
+            return Err(format!("crop '{}' should be top,left,height,width", s));
+        };
+
+        let number = |n: &str| format!("crop '{}' should be made of numbers, not '{}'", s, n);
+        return Ok(Crop {
+            top: top.parse().map_err(|_| number(top))?,
+            left: left.parse().map_err(|_| number(left))?,
+            height: height.parse().map_err(|_| number(height))?,
+            width: width.parse().map_err(|_| number(width))?,
+        });
+    }
+}
+
+// Collects frames from a simulation as it runs.
+#[derive(Debug)]
+pub struct Recorder {
+    // Only one in every this many frames is kept.
+    every: usize,
+    crop: Option<Crop>,
+    // Number of frames offered so far.
+    offered: usize,
+    frames: Vec<Frame>,
+}
+
+impl Recorder {
+    pub fn new(every: usize, crop: Option<Crop>) -> Recorder {
+        assert!(every > 0, "should keep at least every frame");
+        Recorder {
+            every: every,
+            crop: crop,
+            offered: 0,
+            frames: Vec::new(),
+        }
+    }
+
+    // Offers the next frame of the simulation. It's only drawn if it's kept,
+    // so simulations don't pay for frames that are skipped.
+    pub fn record(&mut self, draw: impl FnOnce() -> Frame) {
+        if self.offered.is_multiple_of(self.every) {
+            let frame = draw();
+            self.frames.push(match self.crop {
+                Some(crop) => frame.crop(crop),
+                None => frame,
+            });
+        }
+        self.offered += 1;
+    }
+
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+}
+
+// Ways to write out recorded frames.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    // All frames in one text file, separated by blank lines.
+    Text,
+    // One black and white image per frame.
+    Pbm,
+    // One colour image per frame.
+    Ppm,
+    // One animated image.
+    Gif,
+}
+
+impl Format {
+    // Format named by a file's extension.
+    pub fn from_path(path: &Path) -> Option<Format> {
+        match path.extension()?.to_str()? {
+            "txt" => Some(Format::Text),
+            "pbm" => Some(Format::Pbm),
+            "ppm" => Some(Format::Ppm),
+            "gif" => Some(Format::Gif),
+            _ => None,
+        }
+    }
+}
+
+// Lines the frames up on canvases big enough for all of them, with the space
+// around each frame left blank.
+fn canvases(frames: &[Frame]) -> Vec<Grid<u8>> {
+    let top = frames.iter().map(|f| f.top).min().unwrap_or(0);
+    let left = frames.iter().map(|f| f.left).min().unwrap_or(0);
+    let bottom = frames.iter().map(Frame::bottom).max().unwrap_or(0);
+    let right = frames.iter().map(Frame::right).max().unwrap_or(0);
+    let height = (bottom - top) as usize;
+    let width = (right - left) as usize;
+
+    frames
+        .iter()
+        .map(|frame| {
+            let mut canvas = Grid::new(height, width, b' ');
+            for ((r, c), &cell) in frame.cells.iter() {
+                canvas[(
+                    (frame.top - top) as usize + r,
+                    (frame.left - left) as usize + c,
+                )] = cell;
+            }
+            canvas
+        })
+        .collect()
+}
+
+fn palette_index(cell: u8) -> u8 {
+    PALETTE
+        .iter()
+        .position(|(cells, _)| cells.contains(&cell))
+        .unwrap_or(PALETTE.len() - 1) as u8
+}
+
+// Each cell of a canvas turned into scale x scale pixels, row by row.
+fn pixels<T: Copy>(canvas: &Grid<u8>, scale: usize, pixel: impl Fn(u8) -> T) -> Vec<T> {
+    let mut pixels = Vec::with_capacity(canvas.height() * canvas.width() * scale * scale);
+    for row in canvas.rows() {
+        for _ in 0..scale {
+            for &cell in row {
+                pixels.extend(std::iter::repeat_n(pixel(cell), scale));
+            }
+        }
+    }
+    return pixels;
+}
+
+// Path of the nth image in a sequence, e.g. frames-0001.ppm for frames.ppm.
+fn numbered_path(path: &Path, n: usize) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let extension = path.extension().unwrap_or_default().to_string_lossy();
+    path.with_file_name(format!("{}-{:04}.{}", stem, n, extension))
+}
+
+fn write_pnm(path: &Path, canvas: &Grid<u8>, format: Format, scale: usize) -> io::Result<()> {
+    let mut out = BufWriter::new(File::create(path)?);
+    let (width, height) = (canvas.width() * scale, canvas.height() * scale);
+
+    match format {
+        Format::Pbm => {
+            // Rows of bits, 1 for black, each padded out to a whole byte.
+            write!(out, "P4\n{} {}\n", width, height)?;
+            let black = pixels(canvas, scale, |cell| !matches!(cell, b' ' | b'.'));
+            for row in black.chunks(width.max(1)) {
+                let bytes: Vec<u8> = row
+                    .chunks(8)
+                    .map(|bits| {
+                        bits.iter()
+                            .enumerate()
+                            .fold(0, |byte, (i, &b)| byte | ((b as u8) << (7 - i)))
+                    })
+                    .collect();
+                out.write_all(&bytes)?;
+            }
+        }
+        _ => {
+            write!(out, "P6\n{} {}\n255\n", width, height)?;
+            let rgb = pixels(canvas, scale, |cell| {
+                PALETTE[palette_index(cell) as usize].1
+            });
+            out.write_all(&rgb.concat())?;
+        }
+    }
+
+    return out.flush();
+}
+
+// Writes out the frames in a format, scaling each cell up to scale x scale
+// pixels in images. Image sequences are written next to the path, numbered
+// from 1. Returns the files written.
+pub fn export(
+    frames: &[Frame],
+    path: &Path,
+    format: Format,
+    scale: usize,
+) -> io::Result<Vec<PathBuf>> {
+    let canvases = canvases(frames);
+
+    match format {
+        Format::Text => {
+            let blocks: Vec<String> = canvases
+                .iter()
+                .map(|canvas| canvas.render(|&cell| cell as char))
+                .collect();
+            std::fs::write(path, blocks.join("\n"))?;
+            Ok(vec![path.to_path_buf()])
+        }
+        Format::Pbm | Format::Ppm => {
+            let mut paths = Vec::new();
+            for (i, canvas) in canvases.iter().enumerate() {
+                let path = numbered_path(path, i + 1);
+                write_pnm(&path, canvas, format, scale)?;
+                paths.push(path);
+            }
+            Ok(paths)
+        }
+        Format::Gif => {
+            let size = |n: usize| {
+                u16::try_from(n * scale).map_err(|_| {
+                    io::Error::new(io::ErrorKind::InvalidInput, "frames are too big for a GIF")
+                })
+            };
+            let (width, height) = match canvases.first() {
+                Some(canvas) => (size(canvas.width())?, size(canvas.height())?),
+                None => (0, 0),
+            };
+            let palette: Vec<[u8; 3]> = PALETTE.iter().map(|&(_, rgb)| rgb).collect();
+            let images: Vec<Vec<u8>> = canvases
+                .iter()
+                .map(|canvas| pixels(canvas, scale, palette_index))
+                .collect();
+
+            let mut out = BufWriter::new(File::create(path)?);
+            gif::write(&mut out, width, height, &palette, &images, GIF_DELAY)?;
+            out.flush()?;
+            Ok(vec![path.to_path_buf()])
+        }
+    }
+}
@@ -0,0 +1,278 @@
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+// Codes in GIF's LZW compression are at most 12 bits long.
+const MAX_CODES: u16 = 1 << 12;
+
+// Packs variable-length codes into bytes, least significant bit first.
+struct BitWriter {
+    bytes: Vec<u8>,
+    bits: u32,
+    len: u8,
+}
+
+impl BitWriter {
+    fn new() -> BitWriter {
+        BitWriter {
+            bytes: Vec::new(),
+            bits: 0,
+            len: 0,
+        }
+    }
+
+    fn write(&mut self, code: u16, size: u8) {
+        self.bits |= (code as u32) << self.len;
+        self.len += size;
+        while self.len >= 8 {
+            self.bytes.push(self.bits as u8);
+            self.bits >>= 8;
+            self.len -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.len > 0 {
+            self.bytes.push(self.bits as u8);
+        }
+        return self.bytes;
+    }
+}
+
+// Compresses palette indices with GIF's variant of LZW. Every index should be
+// below 2^min_code_size.
+fn compress(indices: &[u8], min_code_size: u8) -> Vec<u8> {
+    let clear = 1u16 << min_code_size;
+    let end = clear + 1;
+
+    // codes maps (code for a string, next index) to the code for the string
+    // extended by that index.
+    let mut codes: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut next_code = end + 1;
+    let mut size = min_code_size + 1;
+
+    let mut out = BitWriter::new();
+    out.write(clear, size);
+
+    let mut current: Option<u16> = None;
+    for &index in indices {
+        let Some(prefix) = current else {
+            current = Some(index as u16);
+            continue;
+        };
+        if let Some(&code) = codes.get(&(prefix, index)) {
+            current = Some(code);
+            continue;
+        }
+
+        out.write(prefix, size);
+        if next_code < MAX_CODES {
+            codes.insert((prefix, index), next_code);
+            next_code += 1;
+            // The decoder adds each code one step behind us, so the code size
+            // goes up once the code it's about to add no longer fits.
+            if next_code > 1 << size && size < 12 {
+                size += 1;
+            }
+        } else {
+            // Out of codes, so start again with a fresh table.
+            out.write(clear, size);
+            codes.clear();
+            next_code = end + 1;
+            size = min_code_size + 1;
+        }
+        current = Some(index as u16);
+    }
+
+    if let Some(prefix) = current {
+        out.write(prefix, size);
+    }
+    out.write(end, size);
+
+    return out.finish();
+}
+
+// Writes data as a series of sub-blocks of at most 255 bytes, each preceded
+// by its length and ended by an empty one.
+fn write_blocks(out: &mut impl Write, data: &[u8]) -> io::Result<()> {
+    for chunk in data.chunks(255) {
+        out.write_all(&[chunk.len() as u8])?;
+        out.write_all(chunk)?;
+    }
+    out.write_all(&[0])
+}
+
+// Writes an animation that loops forever. Each frame is width x height
+// indices into the palette, row by row, and is shown for delay hundredths of a
+// second. The palette can have up to 256 colours.
+pub fn write(
+    out: &mut impl Write,
+    width: u16,
+    height: u16,
+    palette: &[[u8; 3]],
+    frames: &[Vec<u8>],
+    delay: u16,
+) -> io::Result<()> {
+    assert!(
+        palette.len() <= 256,
+        "GIF palette should have at most 256 colours"
+    );
+
+    // The colour table has 2^(bits + 1) entries, at least 2 of them.
+    let mut bits = 0;
+    while 2 << bits < palette.len() {
+        bits += 1;
+    }
+    let mut table = vec![[0; 3]; 2 << bits];
+    table[..palette.len()].copy_from_slice(palette);
+    // LZW codes start at 2 bits, even for 2 colours.
+    let min_code_size = (bits + 1).max(2);
+
+    // Header and logical screen descriptor, with a global colour table.
+    out.write_all(b"GIF89a")?;
+    out.write_all(&width.to_le_bytes())?;
+    out.write_all(&height.to_le_bytes())?;
+    out.write_all(&[0x80 | 0x70 | bits, 0, 0])?;
+    out.write_all(&table.concat())?;
+
+    // Application extension to loop forever.
+    out.write_all(&[0x21, 0xff, 0x0b])?;
+    out.write_all(b"NETSCAPE2.0")?;
+    out.write_all(&[0x03, 0x01, 0x00, 0x00, 0x00])?;
+
+    for frame in frames {
+        assert_eq!(
+            frame.len(),
+            width as usize * height as usize,
+            "frame should be {}x{}",
+            width,
+            height
+        );
+
+        // Graphic control extension, for the delay.
+        out.write_all(&[0x21, 0xf9, 0x04, 0x00])?;
+        out.write_all(&delay.to_le_bytes())?;
+        out.write_all(&[0x00, 0x00])?;
+
+        // Image descriptor covering the whole screen, then the image.
+        out.write_all(&[0x2c, 0, 0, 0, 0])?;
+        out.write_all(&width.to_le_bytes())?;
+        out.write_all(&height.to_le_bytes())?;
+        out.write_all(&[0x00, min_code_size])?;
+        write_blocks(out, &compress(frame, min_code_size))?;
+    }
+
+    out.write_all(&[0x3b])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Decodes what compress makes, the way a GIF reader would.
+    fn decompress(bytes: &[u8], min_code_size: u8) -> Vec<u8> {
+        let clear = 1u16 << min_code_size;
+        let end = clear + 1;
+        let reset = || -> Vec<Vec<u8>> { (0..=end).map(|i| vec![i as u8]).collect() };
+
+        let mut table = reset();
+        let mut size = min_code_size + 1;
+        let mut out = Vec::new();
+        let mut previous: Option<Vec<u8>> = None;
+
+        let (mut bits, mut len, mut i) = (0u32, 0u8, 0);
+        loop {
+            while len < size {
+                bits |= (bytes[i] as u32) << len;
+                len += 8;
+                i += 1;
+            }
+            let code = (bits & ((1 << size) - 1)) as u16;
+            bits >>= size;
+            len -= size;
+
+            if code == clear {
+                table = reset();
+                size = min_code_size + 1;
+                previous = None;
+                continue;
+            }
+            if code == end {
+                return out;
+            }
+
+            let string = match (table.get(code as usize), &previous) {
+                (Some(string), _) => string.clone(),
+                // The code the encoder has only just added: the last string
+                // and its own first index.
+                (None, Some(prev)) => [prev.as_slice(), &prev[..1]].concat(),
+                (None, None) => panic!("code {} before any string", code),
+            };
+            if let Some(prev) = previous {
+                if table.len() < MAX_CODES as usize {
+                    table.push([prev.as_slice(), &string[..1]].concat());
+                }
+            }
+            if table.len() == 1 << size && size < 12 {
+                size += 1;
+            }
+            out.extend_from_slice(&string);
+            previous = Some(string);
+        }
+    }
+
+    #[test]
+    fn bit_writer_packs_least_significant_first() {
+        let mut out = BitWriter::new();
+        out.write(0b101, 3);
+        out.write(0b11, 2);
+        out.write(0b1_0000_0001, 9);
+        assert_eq!(out.finish(), [0b0011_1101, 0b0010_0000]);
+        assert!(BitWriter::new().finish().is_empty());
+    }
+
+    #[test]
+    fn compress_known_bytes() {
+        // Just a clear code, the index and an end code, at 3 bits each.
+        assert_eq!(compress(&[1], 2), [0b0100_1100, 0b0000_0001]);
+        assert_eq!(compress(&[], 2), [0b0010_1100]);
+    }
+
+    #[test]
+    fn compress_round_trips() {
+        let repeated = vec![3; 1000];
+        let counting: Vec<u8> = (0..5000).map(|i| (i * 7 % 13) as u8).collect();
+        for (indices, min_code_size) in [
+            (vec![0, 1, 0, 1, 0, 1, 0, 1], 2),
+            (repeated, 2),
+            (counting, 4),
+        ] {
+            let compressed = compress(&indices, min_code_size);
+            assert_eq!(decompress(&compressed, min_code_size), indices);
+        }
+    }
+
+    #[test]
+    fn compress_starts_again_when_out_of_codes() {
+        // Enough different pairs of indices to use up all 4096 codes.
+        let mut indices = Vec::new();
+        for a in 0..=255u8 {
+            for b in 0..=255u8 {
+                indices.extend([a, b]);
+            }
+        }
+        let compressed = compress(&indices, 8);
+        assert_eq!(decompress(&compressed, 8), indices);
+    }
+
+    #[test]
+    fn blocks_are_at_most_255_bytes() {
+        let mut out = Vec::new();
+        write_blocks(&mut out, &[7; 300]).unwrap();
+        assert_eq!(out.len(), 1 + 255 + 1 + 45 + 1);
+        assert_eq!((out[0], out[256], out[302]), (255, 45, 0));
+
+        let mut out = Vec::new();
+        write_blocks(&mut out, &[]).unwrap();
+        assert_eq!(out, [0]);
+    }
+}
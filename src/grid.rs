@@ -0,0 +1,314 @@
+use std::ops::{Index, IndexMut};
+
+use crate::parse::{lines, Line};
+use crate::point::{Direction, Point2};
+use crate::ParseError;
+
+// Offsets to the orthogonal neighbours of a position, as (row, column),
+// clockwise from up.
+pub const NEIGHBOURS_4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+// Offsets to the orthogonal and diagonal neighbours of a position, as
+// (row, column), clockwise from up.
+pub const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+// Point of a (row, column) position, with x the column and y the row, for
+// working out distances and directions between positions.
+pub fn to_point(pos: (usize, usize)) -> Point2 {
+    Point2::new(pos.1 as i64, pos.0 as i64)
+}
+
+// (row, column) position of a point, if it's not above or left of the map.
+pub fn from_point(point: Point2) -> Option<(usize, usize)> {
+    Some((point.y.try_into().ok()?, point.x.try_into().ok()?))
+}
+
+// A rectangular map of cells, indexed by (row, column) from the top-left.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    height: usize,
+    width: usize,
+    // Cells row by row.
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(height: usize, width: usize, fill: T) -> Grid<T> {
+        Grid {
+            height: height,
+            width: width,
+            cells: vec![fill; height * width],
+        }
+    }
+
+    // Reads a map from lines of text that may be of different lengths, as in
+    // day 22. Rows shorter than the longest one are padded at the end.
+    pub fn from_lines_padded<'a, F>(
+        lines: impl IntoIterator<Item = Line<'a>>,
+        pad: T,
+        cell: F,
+    ) -> Result<Grid<T>, ParseError>
+    where
+        F: FnMut(Line<'a>, usize, char) -> Result<T, ParseError>,
+    {
+        let rows = parse_rows(lines, cell)?;
+        let height = rows.len();
+        let width = rows.iter().map(|(_, row)| row.len()).max().unwrap_or(0);
+
+        let mut cells = Vec::with_capacity(rows.len() * width);
+        for (_, mut row) in rows {
+            row.resize(width, pad.clone());
+            cells.append(&mut row);
+        }
+
+        return Ok(Grid {
+            height: height,
+            width: width,
+            cells: cells,
+        });
+    }
+}
+
+impl<T> Grid<T> {
+    // Builds a map by calling a function for each position.
+    pub fn from_fn(
+        height: usize,
+        width: usize,
+        mut cell: impl FnMut((usize, usize)) -> T,
+    ) -> Grid<T> {
+        Grid {
+            height: height,
+            width: width,
+            cells: (0..height * width)
+                .map(|i| cell((i / width, i % width)))
+                .collect(),
+        }
+    }
+
+    // Reads a map from a whole puzzle input, one row per line.
+    pub fn parse<F>(input: &str, cell: F) -> Result<Grid<T>, ParseError>
+    where
+        F: FnMut(Line, usize, char) -> Result<T, ParseError>,
+    {
+        Grid::from_lines(lines(input), cell)
+    }
+
+    // Reads a map from lines of text, which must all be as long as the first.
+    // The cell function is given each character along with its line and char
+    // index, so that it can point errors at it.
+    pub fn from_lines<'a, F>(
+        lines: impl IntoIterator<Item = Line<'a>>,
+        cell: F,
+    ) -> Result<Grid<T>, ParseError>
+    where
+        F: FnMut(Line<'a>, usize, char) -> Result<T, ParseError>,
+    {
+        let rows = parse_rows(lines, cell)?;
+        let height = rows.len();
+        let width = rows.first().map_or(0, |(_, row)| row.len());
+
+        let mut cells = Vec::with_capacity(rows.len() * width);
+        for (line, mut row) in rows {
+            if row.len() < width {
+                return Err(line.error_at_end("a row as long as the first"));
+            }
+            if row.len() > width {
+                return Err(line.error_at_char(width, "the end of the row"));
+            }
+            cells.append(&mut row);
+        }
+
+        return Ok(Grid {
+            height: height,
+            width: width,
+            cells: cells,
+        });
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn contains(&self, pos: (usize, usize)) -> bool {
+        pos.0 < self.height && pos.1 < self.width
+    }
+
+    pub fn get(&self, pos: (usize, usize)) -> Option<&T> {
+        if self.contains(pos) {
+            Some(&self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: (usize, usize)) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    // Moves from a position by a (row, column) offset, if that stays on the
+    // map.
+    pub fn offset(&self, pos: (usize, usize), delta: (isize, isize)) -> Option<(usize, usize)> {
+        let row = pos.0.checked_add_signed(delta.0)?;
+        let col = pos.1.checked_add_signed(delta.1)?;
+        if self.contains((row, col)) {
+            Some((row, col))
+        } else {
+            None
+        }
+    }
+
+    // Moves from a position one step in a direction, if that stays on the
+    // map. Up is towards row 0.
+    pub fn step(&self, pos: (usize, usize), dir: Direction) -> Option<(usize, usize)> {
+        let delta = dir.delta();
+        self.offset(pos, (delta.y as isize, delta.x as isize))
+    }
+
+    // Position of a signed (row, column), wrapping around the edges of the
+    // map as if it repeated forever. The map should not be empty.
+    pub fn wrap(&self, row: isize, col: isize) -> (usize, usize) {
+        (
+            row.rem_euclid(self.height as isize) as usize,
+            col.rem_euclid(self.width as isize) as usize,
+        )
+    }
+
+    pub fn get_wrapping(&self, row: isize, col: isize) -> &T {
+        &self[self.wrap(row, col)]
+    }
+
+    // Orthogonal neighbours of a position that are on the map.
+    pub fn neighbours_4(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBOURS_4
+            .iter()
+            .filter_map(move |&delta| self.offset(pos, delta))
+    }
+
+    // Orthogonal and diagonal neighbours of a position that are on the map.
+    pub fn neighbours_8(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        NEIGHBOURS_8
+            .iter()
+            .filter_map(move |&delta| self.offset(pos, delta))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        // chunks_exact panics on 0, but there is nothing to iterate then.
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.cells.iter().skip(col).step_by(self.width.max(1))
+    }
+
+    // Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |r| (0..width).map(move |c| (r, c)))
+    }
+
+    // Every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    // Position of the first cell, row by row, that matches.
+    pub fn position(&self, pred: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.cells
+            .iter()
+            .position(pred)
+            .map(|i| (i / self.width, i % self.width))
+    }
+
+    // Adds a row to the bottom of the map, e.g. to grow the chamber in day 17.
+    pub fn push_row(&mut self, row: impl IntoIterator<Item = T>) {
+        let len = self.cells.len();
+        self.cells.extend(row);
+        assert_eq!(
+            self.cells.len() - len,
+            self.width,
+            "row should be as wide as the grid"
+        );
+        self.height += 1;
+    }
+
+    // Draws the map as text, one line per row.
+    pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        let mut out = String::with_capacity(self.height * (self.width + 1));
+        for row in self.rows() {
+            out.extend(row.iter().map(&mut cell));
+            out.push('\n');
+        }
+        return out;
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: (usize, usize)) -> &T {
+        assert!(
+            self.contains(pos),
+            "position {:?} should be within the {}x{} grid",
+            pos,
+            self.height,
+            self.width
+        );
+        &self.cells[pos.0 * self.width + pos.1]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut T {
+        assert!(
+            self.contains(pos),
+            "position {:?} should be within the {}x{} grid",
+            pos,
+            self.height,
+            self.width
+        );
+        &mut self.cells[pos.0 * self.width + pos.1]
+    }
+}
+
+// Maps each character of each line to a cell, keeping the lines for errors.
+fn parse_rows<'a, T, F>(
+    lines: impl IntoIterator<Item = Line<'a>>,
+    mut cell: F,
+) -> Result<Vec<(Line<'a>, Vec<T>)>, ParseError>
+where
+    F: FnMut(Line<'a>, usize, char) -> Result<T, ParseError>,
+{
+    lines
+        .into_iter()
+        .map(|line| {
+            let row = line
+                .text
+                .chars()
+                .enumerate()
+                .map(|(i, c)| cell(line, i, c))
+                .collect::<Result<Vec<T>, _>>()?;
+            Ok((line, row))
+        })
+        .collect()
+}
@@ -0,0 +1,149 @@
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::answers::{escape, unescape};
+use crate::data;
+use crate::Puzzle;
+
+// What the puzzle site said about a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    // Wrong, without saying which way.
+    Wrong,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too-high"),
+            Verdict::TooLow => write!(f, "too-low"),
+            Verdict::Wrong => write!(f, "wrong"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Verdict, String> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too-high" => Ok(Verdict::TooHigh),
+            "too-low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            _ => Err(format!(
+                "verdict '{}' should be correct, too-high, too-low or wrong",
+                s
+            )),
+        }
+    }
+}
+
+// An answer that was submitted for a puzzle, and what came of it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Guess {
+    pub puzzle: Puzzle,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+// Guesses are for the real inputs, so like the accepted answers they live
+// next to them rather than in the repo.
+pub fn path() -> PathBuf {
+    data::dir().join("guesses.txt")
+}
+
+// Reads the guess log, which has one "<puzzle> <verdict> <answer>" line per
+// submission, oldest first. A missing file has no guesses.
+pub fn read(path: &Path) -> io::Result<Vec<Guess>> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+
+    let mut guesses = Vec::new();
+    for line in contents.lines().filter(|l| !l.is_empty()) {
+        let guess = line.split_once(' ').and_then(|(puzzle, rest)| {
+            let (verdict, answer) = rest.split_once(' ')?;
+            Some(Guess {
+                puzzle: puzzle.parse().ok()?,
+                verdict: verdict.parse().ok()?,
+                answer: unescape(answer),
+            })
+        });
+        let Some(guess) = guess else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("bad guesses line '{}'", line),
+            ));
+        };
+        guesses.push(guess);
+    }
+
+    return Ok(guesses);
+}
+
+// Adds a guess to the end of the log, creating it if need be.
+pub fn append(path: &Path, guess: &Guess) -> io::Result<()> {
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    writeln!(
+        file,
+        "{} {} {}",
+        guess.puzzle,
+        guess.verdict,
+        escape(&guess.answer)
+    )
+}
+
+// Reasons to doubt a new answer to a puzzle, going by the earlier guesses at
+// it: it isn't the one that was correct, it was already rejected, or it's
+// outside the bounds that the too high and too low guesses set. Bounds only
+// apply to numbers.
+pub fn warnings(guesses: &[Guess], puzzle: Puzzle, answer: &str) -> Vec<String> {
+    let guesses: Vec<&Guess> = guesses.iter().filter(|g| g.puzzle == puzzle).collect();
+    let mut warnings = Vec::new();
+
+    if let Some(correct) = guesses.iter().find(|g| g.verdict == Verdict::Correct) {
+        if correct.answer != answer {
+            warnings.push(format!(
+                "differs from {}, which was correct",
+                correct.answer
+            ));
+        }
+        return warnings;
+    }
+
+    if let Some(rejected) = guesses.iter().find(|g| g.answer == answer) {
+        warnings.push(format!(
+            "was already guessed and marked {}",
+            rejected.verdict
+        ));
+    }
+
+    let Ok(n) = answer.parse::<i64>() else {
+        return warnings;
+    };
+    let bound = |verdict| {
+        guesses
+            .iter()
+            .filter(move |g| g.verdict == verdict)
+            .filter_map(|g| g.answer.parse::<i64>().ok())
+    };
+    // Only the tightest bound each way is worth mentioning.
+    if let Some(high) = bound(Verdict::TooHigh).filter(|&high| n > high).min() {
+        warnings.push(format!("is above {}, which was too high", high));
+    }
+    if let Some(low) = bound(Verdict::TooLow).filter(|&low| n < low).max() {
+        warnings.push(format!("is below {}, which was too low", low));
+    }
+
+    return warnings;
+}
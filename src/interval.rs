@@ -0,0 +1,314 @@
+// An inclusive range of integers, start..=end. It's empty if end is before
+// start.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Interval {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl Interval {
+    pub const fn new(start: i64, end: i64) -> Interval {
+        Interval {
+            start: start,
+            end: end,
+        }
+    }
+
+    // Interval of just one number.
+    pub const fn point(n: i64) -> Interval {
+        Interval::new(n, n)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.end < self.start
+    }
+
+    // Number of integers in the interval.
+    pub fn len(&self) -> u64 {
+        if self.is_empty() {
+            0
+        } else {
+            self.end.abs_diff(self.start) + 1
+        }
+    }
+
+    pub fn contains(&self, n: i64) -> bool {
+        self.start <= n && n <= self.end
+    }
+
+    // Whether every number in the other interval is also in this one.
+    pub fn covers(&self, other: Interval) -> bool {
+        other.is_empty() || (self.start <= other.start && other.end <= self.end)
+    }
+
+    // Whether the intervals have any number in common.
+    pub fn overlaps(&self, other: Interval) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    // Numbers in both intervals. This may be empty.
+    pub fn intersection(&self, other: Interval) -> Interval {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+}
+
+// A set of integers, kept as the fewest intervals that cover it: sorted, not
+// empty, and neither overlapping nor touching each other.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RangeSet {
+    intervals: Vec<Interval>,
+}
+
+impl RangeSet {
+    pub fn new() -> RangeSet {
+        RangeSet::default()
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    // Number of integers in the set.
+    pub fn len(&self) -> u64 {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn contains(&self, n: i64) -> bool {
+        !self.overlapping(Interval::point(n)).is_empty()
+    }
+
+    // Adds every number in an interval to the set.
+    pub fn insert(&mut self, interval: Interval) {
+        *self = self.union(&RangeSet::from(interval));
+    }
+
+    // Takes every number in an interval out of the set.
+    pub fn remove(&mut self, interval: Interval) {
+        *self = self.difference(&RangeSet::from(interval));
+    }
+
+    pub fn union(&self, other: &RangeSet) -> RangeSet {
+        self.intervals
+            .iter()
+            .chain(other.intervals.iter())
+            .copied()
+            .collect()
+    }
+
+    pub fn intersection(&self, other: &RangeSet) -> RangeSet {
+        let mut intervals = Vec::new();
+        for &interval in &self.intervals {
+            intervals.extend(
+                other
+                    .overlapping(interval)
+                    .iter()
+                    .map(|&i| i.intersection(interval)),
+            );
+        }
+
+        return RangeSet {
+            intervals: intervals,
+        };
+    }
+
+    // Numbers in this set that aren't in the other.
+    pub fn difference(&self, other: &RangeSet) -> RangeSet {
+        RangeSet {
+            intervals: self
+                .intervals
+                .iter()
+                .flat_map(|&interval| other.gaps(interval))
+                .collect(),
+        }
+    }
+
+    // Stretches of numbers within the given interval that aren't in the set,
+    // in order.
+    pub fn gaps(&self, within: Interval) -> Vec<Interval> {
+        let mut gaps = Vec::new();
+        let mut start = within.start;
+
+        for interval in self.overlapping(within) {
+            if start < interval.start {
+                gaps.push(Interval::new(start, interval.start - 1));
+            }
+            match interval.end.checked_add(1) {
+                Some(next) => start = next,
+                // Nothing can come after this interval.
+                None => return gaps,
+            }
+        }
+        if start <= within.end {
+            gaps.push(Interval::new(start, within.end));
+        }
+
+        return gaps;
+    }
+
+    // Intervals of the set that have numbers in common with the given one.
+    fn overlapping(&self, interval: Interval) -> &[Interval] {
+        if interval.is_empty() {
+            return &[];
+        }
+
+        let first = self.intervals.partition_point(|i| i.end < interval.start);
+        let last = self.intervals.partition_point(|i| i.start <= interval.end);
+        return &self.intervals[first..last.max(first)];
+    }
+}
+
+impl From<Interval> for RangeSet {
+    fn from(interval: Interval) -> RangeSet {
+        [interval].into_iter().collect()
+    }
+}
+
+// Collects any intervals, in any order, into a set. This sorts them, so takes
+// O(n log n) time.
+impl FromIterator<Interval> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> RangeSet {
+        let mut sorted: Vec<Interval> = iter.into_iter().filter(|i| !i.is_empty()).collect();
+        sorted.sort_unstable_by_key(|i| i.start);
+
+        let mut intervals: Vec<Interval> = Vec::with_capacity(sorted.len());
+        for interval in sorted {
+            match intervals.last_mut() {
+                // Merge intervals that overlap or touch.
+                Some(last) if interval.start <= last.end.saturating_add(1) => {
+                    last.end = last.end.max(interval.end);
+                }
+                _ => intervals.push(interval),
+            }
+        }
+
+        return RangeSet {
+            intervals: intervals,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Set of the given (start, end) intervals.
+    fn set(intervals: &[(i64, i64)]) -> RangeSet {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end))
+            .collect()
+    }
+
+    fn intervals(set: &RangeSet) -> Vec<(i64, i64)> {
+        set.intervals().iter().map(|i| (i.start, i.end)).collect()
+    }
+
+    #[test]
+    fn collect_merges_overlapping_and_touching() {
+        assert_eq!(intervals(&set(&[(5, 8), (1, 3), (2, 4)])), [(1, 8)]);
+        assert_eq!(intervals(&set(&[(1, 3), (4, 6)])), [(1, 6)]);
+        assert_eq!(intervals(&set(&[(1, 3), (5, 6)])), [(1, 3), (5, 6)]);
+    }
+
+    #[test]
+    fn collect_drops_empty_intervals() {
+        assert_eq!(intervals(&set(&[(3, 2), (1, 1), (10, 0)])), [(1, 1)]);
+        assert!(set(&[(3, 2)]).is_empty());
+    }
+
+    #[test]
+    fn union() {
+        let a = set(&[(1, 3), (10, 12)]);
+        assert_eq!(intervals(&a.union(&set(&[(2, 5)]))), [(1, 5), (10, 12)]);
+        assert_eq!(intervals(&a.union(&set(&[(4, 9)]))), [(1, 12)]);
+        assert_eq!(intervals(&a.union(&set(&[(11, 11)]))), [(1, 3), (10, 12)]);
+        assert_eq!(intervals(&a.union(&set(&[(0, 20)]))), [(0, 20)]);
+        assert_eq!(a.union(&RangeSet::new()), a);
+        assert_eq!(RangeSet::new().union(&a), a);
+    }
+
+    #[test]
+    fn intersection() {
+        let a = set(&[(1, 5), (10, 15)]);
+        assert_eq!(
+            intervals(&a.intersection(&set(&[(4, 11)]))),
+            [(4, 5), (10, 11)]
+        );
+        assert_eq!(intervals(&a.intersection(&set(&[(2, 3)]))), [(2, 3)]);
+        assert_eq!(
+            intervals(&a.intersection(&set(&[(0, 20)]))),
+            [(1, 5), (10, 15)]
+        );
+        // Touching isn't overlapping.
+        assert!(a.intersection(&set(&[(6, 9)])).is_empty());
+        assert_eq!(
+            intervals(&a.intersection(&set(&[(5, 10)]))),
+            [(5, 5), (10, 10)]
+        );
+        assert!(a.intersection(&RangeSet::new()).is_empty());
+    }
+
+    #[test]
+    fn insert() {
+        let mut a = set(&[(1, 3), (7, 9)]);
+        a.insert(Interval::new(4, 6));
+        assert_eq!(intervals(&a), [(1, 9)]);
+        a.insert(Interval::new(2, 8));
+        assert_eq!(intervals(&a), [(1, 9)]);
+        a.insert(Interval::new(12, 11));
+        assert_eq!(intervals(&a), [(1, 9)]);
+        a.insert(Interval::point(11));
+        assert_eq!(intervals(&a), [(1, 9), (11, 11)]);
+    }
+
+    #[test]
+    fn remove() {
+        let mut a = set(&[(1, 10)]);
+        a.remove(Interval::new(4, 6));
+        assert_eq!(intervals(&a), [(1, 3), (7, 10)]);
+        a.remove(Interval::new(3, 7));
+        assert_eq!(intervals(&a), [(1, 2), (8, 10)]);
+        a.remove(Interval::new(6, 5));
+        assert_eq!(intervals(&a), [(1, 2), (8, 10)]);
+        a.remove(Interval::new(0, 20));
+        assert!(a.is_empty());
+    }
+
+    #[test]
+    fn difference() {
+        let a = set(&[(1, 10), (20, 30)]);
+        let b = set(&[(0, 1), (5, 5), (10, 20), (25, 40)]);
+        assert_eq!(intervals(&a.difference(&b)), [(2, 4), (6, 9), (21, 24)]);
+        assert_eq!(a.difference(&RangeSet::new()), a);
+        assert!(RangeSet::new().difference(&a).is_empty());
+    }
+
+    #[test]
+    fn gaps() {
+        let a = set(&[(2, 3), (6, 7)]);
+        let gaps: Vec<(i64, i64)> = a
+            .gaps(Interval::new(0, 9))
+            .iter()
+            .map(|i| (i.start, i.end))
+            .collect();
+        assert_eq!(gaps, [(0, 1), (4, 5), (8, 9)]);
+        assert!(a.gaps(Interval::new(6, 7)).is_empty());
+        assert!(a.gaps(Interval::new(5, 4)).is_empty());
+        assert!(set(&[(i64::MIN, i64::MAX)])
+            .gaps(Interval::new(0, 5))
+            .is_empty());
+    }
+
+    #[test]
+    fn len_and_contains() {
+        let a = set(&[(1, 3), (5, 5)]);
+        assert_eq!(a.len(), 4);
+        assert!(a.contains(3) && a.contains(5));
+        assert!(!a.contains(4) && !a.contains(0) && !a.contains(6));
+        assert_eq!(RangeSet::new().len(), 0);
+    }
+}
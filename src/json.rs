@@ -0,0 +1,50 @@
+use std::fmt::Write;
+use std::time::Duration;
+
+use crate::{Answer, Puzzle};
+
+// Quotes a string as a JSON string literal.
+pub fn string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if c.is_control() => {
+                write!(out, "\\u{:04x}", c as u32).unwrap();
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    return out;
+}
+
+// Formats one solved puzzle as a single-line JSON object, with the elapsed
+// time in seconds. Answers from the cache weren't timed, so have a null
+// elapsed time instead.
+pub fn result(puzzle: Puzzle, answer: &Answer, elapsed: Option<Duration>) -> String {
+    let (answer, answer_type) = match answer {
+        Answer::Number(n) => (n.to_string(), "number"),
+        Answer::Text(s) => (string(s), "text"),
+    };
+    let cached = elapsed.is_none();
+    let elapsed = match elapsed {
+        Some(elapsed) => elapsed.as_secs_f64().to_string(),
+        None => "null".to_string(),
+    };
+
+    return format!(
+        "{{\"day\": {}, \"part\": {}, \"answer\": {}, \"answer_type\": {}, \"elapsed\": {}, \"cached\": {}}}",
+        puzzle.day,
+        string(&puzzle.part.to_string()),
+        answer,
+        string(answer_type),
+        elapsed,
+        cached
+    );
+}
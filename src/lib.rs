@@ -0,0 +1,29 @@
+pub mod answers;
+pub mod batch;
+pub mod bench;
+pub mod cache;
+pub mod config;
+pub mod cycle;
+pub mod data;
+pub mod days;
+pub mod differential;
+pub mod frames;
+pub mod gif;
+pub mod grid;
+pub mod guesses;
+pub mod interval;
+pub mod json;
+pub mod parse;
+pub mod point;
+pub mod rng;
+pub mod scaffold;
+pub mod scan;
+pub mod search;
+mod solution;
+pub mod stats;
+
+pub use parse::{InputError, ParseError};
+pub use solution::{
+    check, panic_message, solve, solve_reference, solve_timed, Answer, Context, Param, Part, Puzzle,
+    Solution, Timing,
+};
@@ -0,0 +1,1150 @@
+use std::collections::HashMap;
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::Path;
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use advent2022::answers;
+use advent2022::batch::{self, PuzzleReport};
+use advent2022::bench::{self, Stats};
+use advent2022::config::{self, InputKind};
+use advent2022::data::{self, Sample};
+use advent2022::differential::{self, Report};
+use advent2022::frames::{self, Crop, Recorder};
+use advent2022::guesses::{self, Guess, Verdict};
+use advent2022::stats::SearchStats;
+use advent2022::{days, json, scaffold, Answer, Puzzle, Timing};
+
+const USAGE: &str = "\
+Usage: aoc <command> [args] [options]
+
+Commands:
+    run <puzzle> [--input PATH]... [--record PATH] [--submitted VERDICT]
+                                   Solve the real input (by default
+                                   data/<puzzle>_input.txt), or the given
+                                   inputs (- for stdin). The real answer is
+                                   checked against earlier guesses, and with
+                                   --submitted it is logged as a guess with
+                                   the site's verdict: correct, too-high,
+                                   too-low or wrong
+    sample <puzzle> [--variant N]  Solve the sample input(s)
+    test <day|puzzle>              Check sample answers against expected outputs
+    check <day|puzzle> [--input PATH]...
+                                   Look for problems with the real input, or
+                                   the given inputs (- for stdin), without
+                                   solving them
+    all [--jobs N] [--timeout SECS]
+                                   Test and run every puzzle, N at a time
+                                   (default one per CPU), giving up on any
+                                   solution that takes longer than SECS
+                                   (default 600), and print a table of the
+                                   results. Each --param only goes to the
+                                   days that have that parameter
+    bench [day|puzzle] [--runs N] [--save-baseline]
+                                   Time repeated runs on the real inputs and
+                                   flag puzzles slower than the saved baseline
+    accept <day|puzzle>            Record the current real answers as accepted
+    verify [day|puzzle]            Check real answers against the accepted ones
+    guesses [day|puzzle]           List the logged guesses and their verdicts
+    params [day]                   List the parameters each day can override
+    gen <day> [--size N] [--seed N]
+                                   Print a random input for the day, scaled by
+                                   the size (default 20, e.g. lines or grid
+                                   side); the same seed (default 0) gives the
+                                   same input. Pipe it to run with --input -
+    diff <day|puzzle> [--runs N] [--size N] [--seed N]
+                                   Check the solution against the day's slow
+                                   reference solution on N generated inputs
+                                   (default 100), with seeds counting up from
+                                   --seed and sizes growing up to --size
+                                   (default 8), and show the first input they
+                                   disagree on, with lines taken out for as
+                                   long as they still disagree
+    new <day> [--samples N]        Add a day: its module, its entry in the
+                                   registry, and empty sample files (numbered
+                                   1 to N if there is more than one sample)
+
+Options:
+    --time                         Report parse and solve times on stderr
+    --no-cache                     Solve again even if the answer for the same
+                                   input and version of the solution is cached
+                                   in data/cache (bench, --record and --stats
+                                   never use the cache)
+    --stats                        Report on stderr how much searching run or
+                                   sample did in days 12, 16, 19 and 24:
+                                   states expanded, peak frontier size, memo
+                                   hits and states cut by each pruning rule,
+                                   and where the cycle day 17 found starts
+                                   and how long it is
+    --param name=value             Override one of a day's parameters (see params)
+    --format text|json             Print answers as text, or as one JSON object
+                                   per line: {day, part, answer, answer_type,
+                                   elapsed, cached}, with elapsed in seconds,
+                                   or null for answers from the cache
+    --record PATH                  Capture the simulation of days 9, 14, 17, 23
+                                   and 24 as it runs, into PATH.txt as text,
+                                   PATH-0001.pbm etc. as black and white
+                                   images, PATH-0001.ppm etc. as colour images,
+                                   or PATH.gif as an animation
+    --every N                      Record only every Nth frame
+    --crop TOP,LEFT,HEIGHT,WIDTH   Record only this part of the simulation, in
+                                   its own coordinates
+    --scale N                      Draw each cell as NxN pixels in images
+
+Puzzles are named like the data files, e.g. 14b or 6a.
+
+Settings are read from aoc.toml, if there is one: the data directory and how
+its files are named, and parameter values for each day, which can differ
+between the samples and the real input. --param overrides them.";
+
+// Number of runs per puzzle when benchmarking, unless --runs is given.
+const DEFAULT_RUNS: usize = 10;
+
+// Size of generated inputs, unless --size is given.
+const DEFAULT_SIZE: usize = 20;
+
+// Seconds a solution gets in run-all before it's given up on, unless
+// --timeout is given. The slowest solutions take a few minutes on a debug
+// build.
+const DEFAULT_TIMEOUT_SECS: u64 = 600;
+
+// Number of inputs to check and their largest size when comparing against the
+// reference solutions, which are slow on big inputs.
+const DEFAULT_DIFF_RUNS: usize = 100;
+const DEFAULT_DIFF_SIZE: usize = 8;
+
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Format, ()> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(()),
+        }
+    }
+}
+
+// Options that can appear anywhere after the command.
+#[derive(Default)]
+struct Options {
+    time: bool,
+    stats: bool,
+    no_cache: bool,
+    format: Format,
+    params: Vec<(String, i64)>,
+    inputs: Vec<String>,
+    variant: Option<u32>,
+    runs: Option<usize>,
+    save_baseline: bool,
+    samples: Option<u32>,
+    record: Option<String>,
+    every: Option<usize>,
+    crop: Option<Crop>,
+    scale: Option<usize>,
+    size: Option<usize>,
+    seed: Option<u64>,
+    jobs: Option<usize>,
+    timeout: Option<u64>,
+    submitted: Option<Verdict>,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.is_empty() {
+        eprintln!("{}", USAGE);
+        return ExitCode::from(2);
+    }
+
+    match run(&args) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("aoc: {}", e);
+            ExitCode::from(2)
+        }
+    }
+}
+
+// Runs the command given on the command line. Returns whether everything it
+// checked passed.
+fn run(args: &[String]) -> Result<bool, String> {
+    let (command, args) = args.split_first().expect("args should not be empty");
+    let (args, options) = parse_options(args)?;
+    config::load()?;
+    if !options.inputs.is_empty() && command != "run" && command != "check" {
+        return Err("--input only applies to run and check".to_string());
+    }
+    check_record(command, &options)?;
+    if options.stats && command != "run" && command != "sample" {
+        return Err("--stats only applies to run and sample".to_string());
+    }
+    if options.submitted.is_some() {
+        if command != "run" || !options.inputs.is_empty() {
+            return Err("--submitted only applies to run on the real input".to_string());
+        }
+        if !options.params.is_empty() {
+            return Err("--param can't be used when logging a guess".to_string());
+        }
+    }
+
+    match command.as_str() {
+        "run" => {
+            let [puzzle] = args[..] else {
+                return Err("usage: aoc run <puzzle>".to_string());
+            };
+            let puzzle = parse_puzzle(puzzle)?;
+            match &options.inputs[..] {
+                [] => {
+                    let path = data::input_path(puzzle);
+                    let (answer, timing) = solve_file(puzzle, &path, InputKind::Real, &options)?;
+                    report(puzzle, None, &answer, &timing, &options);
+                    check_guesses(puzzle, &answer, &options)?;
+                }
+                [input] => {
+                    let (answer, timing) = solve_named(puzzle, input, &options)?;
+                    report(puzzle, None, &answer, &timing, &options);
+                }
+                inputs => {
+                    for input in inputs {
+                        let (answer, timing) = solve_named(puzzle, input, &options)?;
+                        report(puzzle, Some(input), &answer, &timing, &options);
+                    }
+                }
+            }
+            Ok(true)
+        }
+        "sample" => {
+            let [puzzle] = args[..] else {
+                return Err("usage: aoc sample <puzzle> [--variant N]".to_string());
+            };
+            let puzzle = parse_puzzle(puzzle)?;
+            sample(puzzle, &options)
+        }
+        "test" => {
+            require_text(&options)?;
+            let [selector] = args[..] else {
+                return Err("usage: aoc test <day|puzzle>".to_string());
+            };
+            let mut passed = true;
+            for puzzle in select_puzzles(selector)? {
+                passed &= test(puzzle, &options)?;
+            }
+            Ok(passed)
+        }
+        "check" => {
+            require_text(&options)?;
+            let [selector] = args[..] else {
+                return Err("usage: aoc check <day|puzzle> [--input PATH]...".to_string());
+            };
+            let puzzles = select_puzzles(selector)?;
+            let mut passed = true;
+            if options.inputs.is_empty() {
+                for puzzle in puzzles {
+                    let path = data::input_path(puzzle);
+                    let input = read_input(&path)?;
+                    passed &= check(puzzle, &path.display().to_string(), &input);
+                }
+            } else {
+                // Each input is read once, as stdin can't be read again for
+                // the other part.
+                for name in &options.inputs {
+                    let input = read_named(name)?;
+                    for &puzzle in &puzzles {
+                        passed &= check(puzzle, name, &input);
+                    }
+                }
+            }
+            Ok(passed)
+        }
+        "all" => {
+            if !args.is_empty() {
+                return Err("usage: aoc all [--jobs N] [--timeout SECS]".to_string());
+            }
+            all(&options)
+        }
+        "bench" => {
+            require_text(&options)?;
+            let puzzles = match args[..] {
+                [] => days::puzzles().collect(),
+                [selector] => select_puzzles(selector)?,
+                _ => {
+                    return Err(
+                        "usage: aoc bench [day|puzzle] [--runs N] [--save-baseline]".to_string()
+                    )
+                }
+            };
+            // Timing cached answers would be no use.
+            let options = Options {
+                no_cache: true,
+                ..options
+            };
+            bench(&puzzles, &options)
+        }
+        "accept" => {
+            require_text(&options)?;
+            let [selector] = args[..] else {
+                return Err("usage: aoc accept <day|puzzle>".to_string());
+            };
+            require_default_params(&options)?;
+            accept(&select_puzzles(selector)?, &options)
+        }
+        "verify" => {
+            require_text(&options)?;
+            let puzzles = match args[..] {
+                [] => days::puzzles().collect(),
+                [selector] => select_puzzles(selector)?,
+                _ => return Err("usage: aoc verify [day|puzzle]".to_string()),
+            };
+            require_default_params(&options)?;
+            verify(&puzzles, &options)
+        }
+        "guesses" => {
+            require_text(&options)?;
+            let puzzles = match args[..] {
+                [] => days::puzzles().collect(),
+                [selector] => select_puzzles(selector)?,
+                _ => return Err("usage: aoc guesses [day|puzzle]".to_string()),
+            };
+            let guesses = read_guesses(&guesses::path())?;
+            for guess in guesses.iter().filter(|g| puzzles.contains(&g.puzzle)) {
+                let label = format!("{} {}", guess.puzzle, guess.verdict);
+                print_answer(&label, &Answer::Text(guess.answer.clone()));
+            }
+            Ok(true)
+        }
+        "params" => {
+            let selected: Vec<_> = match args[..] {
+                [] => days::DAYS.iter().collect(),
+                [day] => {
+                    let day = day
+                        .parse()
+                        .ok()
+                        .and_then(days::get)
+                        .ok_or(format!("no solution for day {}", day))?;
+                    vec![day]
+                }
+                _ => return Err("usage: aoc params [day]".to_string()),
+            };
+            for day in selected {
+                for param in day.params {
+                    println!(
+                        "{:>2}  {:<28} {}",
+                        day.day,
+                        format!("{}={}", param.name, param.default),
+                        param.description
+                    );
+                }
+            }
+            Ok(true)
+        }
+        "gen" => {
+            let [day] = args[..] else {
+                return Err("usage: aoc gen <day> [--size N] [--seed N]".to_string());
+            };
+            let day = day
+                .parse()
+                .ok()
+                .and_then(days::get)
+                .ok_or(format!("no solution for day {}", day))?;
+            let size = options.size.unwrap_or(DEFAULT_SIZE);
+            if size == 0 {
+                return Err("option --size should be at least 1".to_string());
+            }
+            let input = day
+                .generate(size, options.seed.unwrap_or(0))
+                .ok_or(format!("day {} has no input generator", day.day))?;
+            print!("{}", input);
+            Ok(true)
+        }
+        "diff" => {
+            require_text(&options)?;
+            let [selector] = args[..] else {
+                return Err(
+                    "usage: aoc diff <day|puzzle> [--runs N] [--size N] [--seed N]".to_string(),
+                );
+            };
+            let mut passed = true;
+            for puzzle in select_puzzles(selector)? {
+                passed &= diff(puzzle, &options)?;
+            }
+            Ok(passed)
+        }
+        "new" => {
+            let [day] = args[..] else {
+                return Err("usage: aoc new <day> [--samples N]".to_string());
+            };
+            let day = match day.parse() {
+                Ok(day) if day > 0 => day,
+                _ => return Err(format!("day '{}' should be a positive number", day)),
+            };
+            let samples = options.samples.unwrap_or(1);
+            if samples == 0 {
+                return Err("--samples should be at least 1".to_string());
+            }
+            for path in scaffold::new_day(day, samples)? {
+                println!("wrote {}", path.display());
+            }
+            Ok(true)
+        }
+        "help" | "-h" | "--help" => {
+            println!("{}", USAGE);
+            Ok(true)
+        }
+        _ => Err(format!("unknown command '{}'\n\n{}", command, USAGE)),
+    }
+}
+
+// Splits the options out from the positional arguments.
+fn parse_options(args: &[String]) -> Result<(Vec<&str>, Options), String> {
+    let mut positional = Vec::new();
+    let mut options = Options::default();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--time" => options.time = true,
+            "--stats" => options.stats = true,
+            "--no-cache" => options.no_cache = true,
+            "--format" => options.format = option_value(arg, args.next())?,
+            "--param" => options.params.push(param_value(args.next())?),
+            "--input" => options.inputs.push(option_value(arg, args.next())?),
+            "--variant" => options.variant = Some(option_value(arg, args.next())?),
+            "--runs" => options.runs = Some(option_value(arg, args.next())?),
+            "--save-baseline" => options.save_baseline = true,
+            "--samples" => options.samples = Some(option_value(arg, args.next())?),
+            "--record" => options.record = Some(option_value(arg, args.next())?),
+            "--every" => options.every = Some(option_value(arg, args.next())?),
+            "--crop" => options.crop = Some(crop_value(args.next())?),
+            "--scale" => options.scale = Some(option_value(arg, args.next())?),
+            "--size" => options.size = Some(option_value(arg, args.next())?),
+            "--seed" => options.seed = Some(option_value(arg, args.next())?),
+            "--jobs" => options.jobs = Some(option_value(arg, args.next())?),
+            "--timeout" => options.timeout = Some(option_value(arg, args.next())?),
+            "--submitted" => {
+                let value = args.next().ok_or("option --submitted needs a value")?;
+                options.submitted = Some(value.parse()?);
+            }
+            s if s.starts_with("--") => return Err(format!("unknown option '{}'", s)),
+            s => positional.push(s),
+        }
+    }
+
+    return Ok((positional, options));
+}
+
+// Rejects --format json for commands that report checks rather than answers.
+fn require_text(options: &Options) -> Result<(), String> {
+    match options.format {
+        Format::Text => Ok(()),
+        Format::Json => {
+            Err("--format json only applies to commands that print answers".to_string())
+        }
+    }
+}
+
+// Rejects --param for commands that deal with the accepted answers, which
+// are only valid for the default parameters.
+fn require_default_params(options: &Options) -> Result<(), String> {
+    match options.params[..] {
+        [] => Ok(()),
+        _ => Err("--param can't be used when checking accepted answers".to_string()),
+    }
+}
+
+// Parses the value of --param, given as name=value.
+fn param_value(value: Option<&String>) -> Result<(String, i64), String> {
+    let value = value.ok_or("option --param needs a value")?;
+    match value.split_once('=') {
+        Some((name, n)) => match n.parse() {
+            Ok(n) => Ok((name.to_string(), n)),
+            Err(_) => Err(format!("parameter '{}' should be a number", name)),
+        },
+        None => Err(format!(
+            "option --param should be name=value, got '{}'",
+            value
+        )),
+    }
+}
+
+// Parses the value of --crop, given as top,left,height,width.
+fn crop_value(value: Option<&String>) -> Result<Crop, String> {
+    value.ok_or("option --crop needs a value")?.parse()
+}
+
+// Checks that the recording options make sense together, before anything is
+// solved.
+fn check_record(command: &str, options: &Options) -> Result<(), String> {
+    let Some(path) = &options.record else {
+        if options.every.is_some() || options.crop.is_some() || options.scale.is_some() {
+            return Err("--every, --crop and --scale only apply with --record".to_string());
+        }
+        return Ok(());
+    };
+
+    if command != "run" {
+        return Err("--record only applies to run".to_string());
+    }
+    if options.inputs.len() > 1 {
+        return Err("--record can't be used with more than one --input".to_string());
+    }
+    if options.every == Some(0) {
+        return Err("option --every should be at least 1".to_string());
+    }
+    if options.scale == Some(0) {
+        return Err("option --scale should be at least 1".to_string());
+    }
+    if frames::Format::from_path(Path::new(path)).is_none() {
+        return Err(format!(
+            "--record path '{}' should end in .txt, .pbm, .ppm or .gif",
+            path
+        ));
+    }
+    return Ok(());
+}
+
+fn option_value<T: FromStr>(option: &str, value: Option<&String>) -> Result<T, String> {
+    let value = value.ok_or(format!("option {} needs a value", option))?;
+    value
+        .parse()
+        .map_err(|_| format!("invalid value '{}' for option {}", value, option))
+}
+
+// Parses a puzzle name and checks that there is a solution for it.
+fn parse_puzzle(s: &str) -> Result<Puzzle, String> {
+    let puzzle: Puzzle = s.parse()?;
+    match days::get(puzzle.day) {
+        Some(day) if day.parts.contains(&puzzle.part) => Ok(puzzle),
+        _ => Err(format!("no solution for puzzle {}", puzzle)),
+    }
+}
+
+// Selects either one puzzle ("22a") or both parts of a day ("22").
+fn select_puzzles(s: &str) -> Result<Vec<Puzzle>, String> {
+    match s.parse::<u8>() {
+        Ok(day) => match days::get(day) {
+            Some(day) => Ok(day.puzzles().collect()),
+            None => Err(format!("no solution for day {}", day)),
+        },
+        Err(_) => Ok(vec![parse_puzzle(s)?]),
+    }
+}
+
+fn read_input(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("could not read {}: {}", path.display(), e))
+}
+
+// Reads an input given with --input, where "-" means stdin.
+fn read_named(name: &str) -> Result<String, String> {
+    match name {
+        "-" => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|e| format!("could not read stdin: {}", e))?;
+            Ok(input)
+        }
+        path => read_input(Path::new(path)),
+    }
+}
+
+// Solves an input from any reader. The label names the input in errors.
+fn solve_reader(
+    puzzle: Puzzle,
+    label: &str,
+    reader: &mut dyn Read,
+    kind: InputKind,
+    options: &Options,
+) -> Result<(Answer, Timing), String> {
+    let day = days::get(puzzle.day).ok_or(format!("no solution for puzzle {}", puzzle))?;
+    let params = day.configured_params(kind, &options.params);
+    let mut ctx = day.context(&params)?;
+
+    let recorder = options.record.as_ref().map(|_| {
+        Arc::new(Mutex::new(Recorder::new(
+            options.every.unwrap_or(1),
+            options.crop,
+        )))
+    });
+    if let Some(recorder) = &recorder {
+        ctx = ctx.with_recorder(Arc::clone(recorder));
+    }
+    let stats = options
+        .stats
+        .then(|| Arc::new(Mutex::new(SearchStats::default())));
+    if let Some(stats) = &stats {
+        ctx = ctx.with_stats(Arc::clone(stats));
+    }
+
+    let mut input = String::new();
+    reader
+        .read_to_string(&mut input)
+        .map_err(|e| format!("could not read {}: {}", label, e))?;
+
+    // Recording and counting need the solution to actually run.
+    let use_cache = !options.no_cache && recorder.is_none() && stats.is_none();
+    let solved = day
+        .solve_cached(puzzle.part, &input, &params, use_cache, || {
+            day.solve_timed(puzzle.part, &input, &ctx)
+        })
+        .map_err(|e| format!("{}: {}", label, e))?;
+
+    if let Some(stats) = stats {
+        report_stats(
+            puzzle,
+            label,
+            &stats.lock().expect("stats should not be poisoned"),
+        );
+    }
+
+    if let (Some(path), Some(recorder)) = (&options.record, recorder) {
+        write_frames(
+            Path::new(path),
+            &recorder.lock().expect("recorder should not be poisoned"),
+            options,
+        )?;
+    }
+
+    return Ok(solved);
+}
+
+// Exports the frames captured with --record.
+fn write_frames(path: &Path, recorder: &Recorder, options: &Options) -> Result<(), String> {
+    let format = frames::Format::from_path(path).expect("--record path should have been checked");
+    let written = frames::export(recorder.frames(), path, format, options.scale.unwrap_or(1))
+        .map_err(|e| format!("could not write {}: {}", path.display(), e))?;
+
+    match &written[..] {
+        [file] => eprintln!(
+            "wrote {} frames to {}",
+            recorder.frames().len(),
+            file.display()
+        ),
+        files => eprintln!(
+            "wrote {} frames as {} files next to {}",
+            recorder.frames().len(),
+            files.len(),
+            path.display()
+        ),
+    }
+    return Ok(());
+}
+
+fn solve_file(
+    puzzle: Puzzle,
+    path: &Path,
+    kind: InputKind,
+    options: &Options,
+) -> Result<(Answer, Timing), String> {
+    let mut file =
+        File::open(path).map_err(|e| format!("could not read {}: {}", path.display(), e))?;
+    solve_reader(
+        puzzle,
+        &path.display().to_string(),
+        &mut file,
+        kind,
+        options,
+    )
+}
+
+// Solves an input given with --input, where "-" means stdin.
+fn solve_named(puzzle: Puzzle, name: &str, options: &Options) -> Result<(Answer, Timing), String> {
+    match name {
+        "-" => solve_reader(
+            puzzle,
+            "stdin",
+            &mut io::stdin().lock(),
+            InputKind::Other,
+            options,
+        ),
+        path => solve_file(puzzle, Path::new(path), InputKind::Other, options),
+    }
+}
+
+// Reports the work the solution's searches did. Like the times, this goes to
+// stderr.
+fn report_stats(puzzle: Puzzle, label: &str, stats: &SearchStats) {
+    if stats.is_empty() {
+        eprintln!("{} ({}): no search stats", puzzle, label);
+        return;
+    }
+
+    eprintln!("{} ({}) search stats:", puzzle, label);
+    for line in stats.to_string().lines() {
+        eprintln!("    {}", line);
+    }
+}
+
+// Prints an answer after a label, putting multi-line answers on their own
+// lines.
+fn print_answer(label: &str, answer: &Answer) {
+    let answer = answer.to_string();
+    if answer.contains('\n') {
+        println!("{}:\n{}", label, answer);
+    } else {
+        println!("{}: {}", label, answer);
+    }
+}
+
+// Reports how long solving took if --time was given. This goes to stderr so
+// that stdout still holds just the answers.
+fn report_time(label: &str, timing: &Timing, options: &Options) {
+    if options.time && timing.cached {
+        eprintln!("{}: cached", label);
+    } else if options.time {
+        eprintln!(
+            "{}: parse {:.2?}, solve {:.2?}",
+            label, timing.parse, timing.solve
+        );
+    }
+}
+
+// Prints a solved answer in the chosen format. Text answers are printed bare
+// unless there is a label to tell them apart.
+fn report(
+    puzzle: Puzzle,
+    label: Option<&str>,
+    answer: &Answer,
+    timing: &Timing,
+    options: &Options,
+) {
+    match options.format {
+        Format::Json => {
+            let elapsed = (!timing.cached).then(|| timing.total());
+            println!("{}", json::result(puzzle, answer, elapsed));
+        }
+        Format::Text => match label {
+            Some(label) => print_answer(label, answer),
+            None => println!("{}", answer),
+        },
+    }
+    report_time(label.unwrap_or(&puzzle.to_string()), timing, options);
+}
+
+// Prints a progress message. In JSON mode stdout is kept for the answers, so
+// these go to stderr instead.
+fn status(message: &str, options: &Options) {
+    match options.format {
+        Format::Json => eprintln!("{}", message),
+        Format::Text => println!("{}", message),
+    }
+}
+
+fn find_samples(puzzle: Puzzle) -> Result<Vec<Sample>, String> {
+    data::samples(puzzle).map_err(|e| format!("could not read {}: {}", data::dir().display(), e))
+}
+
+fn sample(puzzle: Puzzle, options: &Options) -> Result<bool, String> {
+    let mut samples = find_samples(puzzle)?;
+    if options.variant.is_some() {
+        samples.retain(|s| s.variant == options.variant);
+    }
+
+    match samples.as_slice() {
+        [] => Err(format!("no matching sample input for puzzle {}", puzzle)),
+        [sample] => {
+            let (answer, timing) = solve_file(puzzle, &sample.input, InputKind::Sample, options)?;
+            report(puzzle, None, &answer, &timing, options);
+            Ok(true)
+        }
+        samples => {
+            for sample in samples {
+                let (answer, timing) =
+                    solve_file(puzzle, &sample.input, InputKind::Sample, options)?;
+                report(puzzle, Some(&sample.name()), &answer, &timing, options);
+            }
+            Ok(true)
+        }
+    }
+}
+
+// Solves every sample of the puzzle and compares the answers against the
+// expected outputs. Returns whether all of them matched.
+fn test(puzzle: Puzzle, options: &Options) -> Result<bool, String> {
+    let samples = find_samples(puzzle)?;
+    if samples.is_empty() {
+        status(&format!("{} ... no samples", puzzle), options);
+        return Ok(true);
+    }
+
+    let mut passed = true;
+    for sample in samples {
+        let expected = read_input(&sample.output)?;
+        let answer = match solve_file(puzzle, &sample.input, InputKind::Sample, options) {
+            Ok((answer, timing)) => {
+                report_time(&sample.name(), &timing, options);
+                answer.to_string()
+            }
+            Err(e) => {
+                status(&format!("{} ... FAILED\n{}", sample.name(), e), options);
+                passed = false;
+                continue;
+            }
+        };
+
+        if answer == expected.trim_end() {
+            status(&format!("{} ... ok", sample.name()), options);
+        } else {
+            status(
+                &format!(
+                    "{} ... FAILED\nexpected:\n{}\ngot:\n{}",
+                    sample.name(),
+                    expected.trim_end(),
+                    answer
+                ),
+                options,
+            );
+            passed = false;
+        }
+    }
+
+    return Ok(passed);
+}
+
+// Lists every problem with an input for a puzzle, without solving it. The
+// label names the input. Returns whether there were none.
+fn check(puzzle: Puzzle, label: &str, input: &str) -> bool {
+    let day = days::get(puzzle.day).expect("puzzle should have a solution");
+    let problems = day.check(puzzle.part, input);
+    match problems.len() {
+        0 => println!("{} ({}) ... ok", puzzle, label),
+        1 => println!("{} ({}) ... 1 problem", puzzle, label),
+        n => println!("{} ({}) ... {} problems", puzzle, label, n),
+    }
+    for problem in &problems {
+        println!("    {}", problem);
+    }
+
+    return problems.is_empty();
+}
+
+// Tests every puzzle against its samples and solves its real input, several
+// puzzles at a time, then prints a table of how each went. Returns whether
+// all of them passed.
+fn all(options: &Options) -> Result<bool, String> {
+    let jobs = match options.jobs {
+        Some(0) => return Err("option --jobs should be at least 1".to_string()),
+        Some(jobs) => jobs,
+        None => thread::available_parallelism().map_or(1, |n| n.get()),
+    };
+    let timeout = match options.timeout {
+        Some(0) => return Err("option --timeout should be at least 1".to_string()),
+        Some(secs) => Duration::from_secs(secs),
+        None => Duration::from_secs(DEFAULT_TIMEOUT_SECS),
+    };
+
+    // Each day only gets the overrides for parameters it has, so one that no
+    // day has would go unused.
+    for (name, _) in &options.params {
+        if !days::DAYS.iter().any(|d| d.params.iter().any(|p| p.name == name)) {
+            return Err(format!("no day has a parameter '{}' (see params)", name));
+        }
+    }
+
+    let puzzles: Vec<Puzzle> = days::puzzles().collect();
+    let finished = AtomicUsize::new(0);
+    let progress = |report: &PuzzleReport| {
+        let finished = finished.fetch_add(1, Ordering::Relaxed) + 1;
+        let result = if report.passed() { "ok" } else { "FAILED" };
+        eprintln!(
+            "[{}/{}] {} ... {}",
+            finished,
+            puzzles.len(),
+            report.puzzle,
+            result
+        );
+    };
+    let use_cache = !options.no_cache;
+    let reports = batch::run(
+        &puzzles,
+        &options.params,
+        jobs,
+        timeout,
+        use_cache,
+        progress,
+    );
+
+    let mut rows = vec![["puzzle", "samples", "time", "answer", "error"].map(String::from)];
+    let mut long_answers = Vec::new();
+    for report in &reports {
+        let samples = match report.samples {
+            Some(true) => "ok",
+            Some(false) => "FAILED",
+            None => "-",
+        };
+        let answer = match &report.answer {
+            Some(answer) if answer.to_string().contains('\n') => {
+                long_answers.push((report.puzzle, answer));
+                "(below)".to_string()
+            }
+            Some(answer) => answer.to_string(),
+            None => "-".to_string(),
+        };
+        let time = if report.cached {
+            "cached".to_string()
+        } else {
+            format!("{:.2?}", report.elapsed)
+        };
+        rows.push([
+            report.puzzle.to_string(),
+            samples.to_string(),
+            time,
+            answer,
+            report.errors.join("; "),
+        ]);
+
+        if let (Format::Json, Some(answer)) = (options.format, &report.answer) {
+            let elapsed = (!report.cached).then_some(report.elapsed);
+            println!("{}", json::result(report.puzzle, answer, elapsed));
+        }
+    }
+
+    // Every column is as wide as its widest cell, apart from the errors at the
+    // end of each line.
+    let widths: Vec<usize> = (0..4)
+        .map(|col| {
+            rows.iter()
+                .map(|row| row[col].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    for row in &rows {
+        let line: Vec<String> = widths
+            .iter()
+            .zip(row)
+            .map(|(&width, cell)| format!("{:<width$}", cell, width = width))
+            .chain([row[4].clone()])
+            .collect();
+        status(line.join("  ").trim_end(), options);
+    }
+    if options.format == Format::Text {
+        for (puzzle, answer) in long_answers {
+            print_answer(&puzzle.to_string(), answer);
+        }
+    }
+
+    let passed = reports.iter().filter(|r| r.passed()).count();
+    status(
+        &format!("{} of {} puzzles passed", passed, reports.len()),
+        options,
+    );
+    return Ok(passed == reports.len());
+}
+
+// Solves each puzzle's real input repeatedly and prints the spread of times.
+// Returns false if any puzzle's median got slower than its baseline.
+fn bench(puzzles: &[Puzzle], options: &Options) -> Result<bool, String> {
+    let runs = options.runs.unwrap_or(DEFAULT_RUNS);
+    if runs == 0 {
+        return Err("option --runs should be at least 1".to_string());
+    }
+
+    let baseline_path = bench::baseline_path();
+    let mut baseline = bench::read_baseline(&baseline_path)
+        .map_err(|e| format!("could not read {}: {}", baseline_path.display(), e))?;
+
+    let mut passed = true;
+    for &puzzle in puzzles {
+        let path = data::input_path(puzzle);
+        if !path.exists() {
+            println!("{:>4}  no input", puzzle.to_string());
+            continue;
+        }
+
+        let input = read_input(&path)?;
+        let mut times = Vec::new();
+        for _ in 0..runs {
+            let label = path.display().to_string();
+            let (_, timing) = solve_reader(
+                puzzle,
+                &label,
+                &mut input.as_bytes(),
+                InputKind::Real,
+                options,
+            )?;
+            times.push(timing.total());
+        }
+        let stats = Stats::from_runs(&times);
+
+        let mut line = format!(
+            "{:>4}  min {:>10.2?}  median {:>10.2?}  max {:>10.2?}",
+            puzzle.to_string(),
+            stats.min,
+            stats.median,
+            stats.max
+        );
+        if let Some(&previous) = baseline.get(&puzzle) {
+            if stats.slower_than(previous) {
+                line += &format!("  SLOWER than baseline {:.2?}", previous);
+                passed = false;
+            }
+        }
+        println!("{}", line);
+
+        if options.save_baseline {
+            baseline.insert(puzzle, stats.median);
+        }
+    }
+
+    if options.save_baseline {
+        bench::write_baseline(&baseline_path, &baseline)
+            .map_err(|e| format!("could not write {}: {}", baseline_path.display(), e))?;
+        println!("saved baseline to {}", baseline_path.display());
+    }
+
+    return Ok(passed);
+}
+
+// Checks a puzzle's solution against its reference solution on generated
+// inputs. Returns whether they agreed on all of them.
+fn diff(puzzle: Puzzle, options: &Options) -> Result<bool, String> {
+    let day = days::get(puzzle.day).ok_or(format!("no solution for puzzle {}", puzzle))?;
+    let ctx = day.context(&day.configured_params(InputKind::Other, &options.params))?;
+    let runs = options.runs.unwrap_or(DEFAULT_DIFF_RUNS);
+    let size = options.size.unwrap_or(DEFAULT_DIFF_SIZE);
+    if runs == 0 {
+        return Err("option --runs should be at least 1".to_string());
+    }
+    if size == 0 {
+        return Err("option --size should be at least 1".to_string());
+    }
+
+    let report = differential::check(
+        day,
+        puzzle.part,
+        &ctx,
+        runs,
+        size,
+        options.seed.unwrap_or(0),
+    );
+    match report {
+        Report::Agreed(runs) => println!("{} ... ok ({} inputs)", puzzle, runs),
+        Report::NoReference => println!("{} ... no reference solution", puzzle),
+        Report::NoGenerator => println!("{} ... no input generator", puzzle),
+        Report::BadInput { seed, size, error } => {
+            println!(
+                "{} ... FAILED\ngenerated input (seed {}, size {}) doesn't parse: {}",
+                puzzle, seed, size, error
+            );
+            return Ok(false);
+        }
+        Report::Mismatch(mismatch) => {
+            println!(
+                "{} ... FAILED on the input from seed {}, size {}\nsolution:  {}\nreference: {}\ninput, shrunk from {} to {} lines:\n{}",
+                puzzle,
+                mismatch.seed,
+                mismatch.size,
+                mismatch.comparison.solution,
+                mismatch.comparison.reference,
+                mismatch.generated_lines,
+                mismatch.input.lines().count(),
+                mismatch.input.trim_end()
+            );
+            return Ok(false);
+        }
+    }
+
+    return Ok(true);
+}
+
+fn read_answers(path: &Path) -> Result<HashMap<Puzzle, String>, String> {
+    answers::read(path).map_err(|e| format!("could not read {}: {}", path.display(), e))
+}
+
+fn read_guesses(path: &Path) -> Result<Vec<Guess>, String> {
+    guesses::read(path).map_err(|e| format!("could not read {}: {}", path.display(), e))
+}
+
+// Warns on stderr if a real answer goes against the earlier guesses at the
+// puzzle, then logs it as a guess if it was marked as submitted. Answers with
+// parameters changed aren't comparable, so they're left alone.
+fn check_guesses(puzzle: Puzzle, answer: &Answer, options: &Options) -> Result<(), String> {
+    if !options.params.is_empty() {
+        return Ok(());
+    }
+
+    let path = guesses::path();
+    let answer = answer.to_string();
+    for warning in guesses::warnings(&read_guesses(&path)?, puzzle, &answer) {
+        eprintln!("warning: {} answer {}", puzzle, warning);
+    }
+
+    if let Some(verdict) = options.submitted {
+        let guess = Guess {
+            puzzle: puzzle,
+            verdict: verdict,
+            answer: answer,
+        };
+        guesses::append(&path, &guess)
+            .map_err(|e| format!("could not write {}: {}", path.display(), e))?;
+        eprintln!("logged {} guess to {}", verdict, path.display());
+    }
+    return Ok(());
+}
+
+// Solves the real inputs and records their answers as the accepted ones.
+fn accept(puzzles: &[Puzzle], options: &Options) -> Result<bool, String> {
+    let answers_path = answers::path();
+    let mut accepted = read_answers(&answers_path)?;
+
+    for &puzzle in puzzles {
+        let (answer, _) = solve_file(puzzle, &data::input_path(puzzle), InputKind::Real, options)?;
+        print_answer(&puzzle.to_string(), &answer);
+        accepted.insert(puzzle, answer.to_string());
+    }
+
+    answers::write(&answers_path, &accepted)
+        .map_err(|e| format!("could not write {}: {}", answers_path.display(), e))?;
+    println!("saved answers to {}", answers_path.display());
+    return Ok(true);
+}
+
+// Solves the real inputs again and compares them against the accepted
+// answers. Returns whether none of them changed.
+fn verify(puzzles: &[Puzzle], options: &Options) -> Result<bool, String> {
+    let accepted = read_answers(&answers::path())?;
+
+    let mut passed = true;
+    for &puzzle in puzzles {
+        let path = data::input_path(puzzle);
+        let Some(expected) = accepted.get(&puzzle) else {
+            println!("{} ... no accepted answer", puzzle);
+            continue;
+        };
+        if !path.exists() {
+            println!("{} ... no input", puzzle);
+            continue;
+        }
+
+        match solve_file(puzzle, &path, InputKind::Real, options) {
+            Ok((answer, _)) if answer.to_string() == *expected => {
+                println!("{} ... ok", puzzle);
+            }
+            Ok((answer, _)) => {
+                println!("{} ... MISMATCH", puzzle);
+                println!("expected:\n{}\ngot:\n{}", expected, answer);
+                passed = false;
+            }
+            Err(e) => {
+                println!("{} ... FAILED\n{}", puzzle, e);
+                passed = false;
+            }
+        }
+    }
+
+    return Ok(passed);
+}
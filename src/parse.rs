@@ -0,0 +1,167 @@
+use std::fmt;
+use std::io;
+use std::str::FromStr;
+
+// A problem with a puzzle input: where it is, and what was expected there.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    // Day whose input this is. The parsers don't know which day they belong
+    // to, so this is filled in by the registry.
+    pub day: Option<u8>,
+    // Position in the input, both starting at 1.
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: &str, found: &str) -> ParseError {
+        ParseError {
+            day: None,
+            line: line,
+            column: column,
+            expected: expected.to_string(),
+            found: found.to_string(),
+        }
+    }
+
+    pub fn in_day(self, day: u8) -> ParseError {
+        ParseError {
+            day: Some(day),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(day) = self.day {
+            write!(f, "day {}, ", day)?;
+        }
+        write!(
+            f,
+            "line {}, column {}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// A problem getting a puzzle input: either it couldn't be read, or it was
+// read but is malformed.
+#[derive(Debug)]
+pub enum InputError {
+    Read(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::Read(e) => write!(f, "could not read input: {}", e),
+            InputError::Parse(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+impl From<io::Error> for InputError {
+    fn from(e: io::Error) -> InputError {
+        InputError::Read(e)
+    }
+}
+
+impl From<ParseError> for InputError {
+    fn from(e: ParseError) -> InputError {
+        InputError::Parse(e)
+    }
+}
+
+// Describes a piece of input for the "found" half of an error.
+fn describe(token: &str) -> String {
+    if token.is_empty() {
+        "end of line".to_string()
+    } else {
+        format!("{:?}", token)
+    }
+}
+
+// One line of a puzzle input, which remembers where it came from so that
+// errors can point into it. Tokens passed to its methods should be slices of
+// the line's text, so that their columns can be worked out.
+#[derive(Clone, Copy, Debug)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    // Column of the start of a token within this line, starting at 1.
+    pub fn column(&self, token: &str) -> usize {
+        let offset = (token.as_ptr() as usize).wrapping_sub(self.text.as_ptr() as usize);
+        if offset <= self.text.len() {
+            self.text[..offset].chars().count() + 1
+        } else {
+            1
+        }
+    }
+
+    // Error pointing at a token that isn't what was expected.
+    pub fn error(&self, token: &str, expected: &str) -> ParseError {
+        ParseError::new(self.number, self.column(token), expected, &describe(token))
+    }
+
+    // Error pointing at the end of the line, where more was expected.
+    pub fn error_at_end(&self, expected: &str) -> ParseError {
+        self.error(&self.text[self.text.len()..], expected)
+    }
+
+    pub fn parse<T: FromStr>(&self, token: &str, expected: &str) -> Result<T, ParseError> {
+        token.parse().map_err(|_| self.error(token, expected))
+    }
+
+    pub fn strip_prefix(&self, token: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+        token
+            .strip_prefix(prefix)
+            .ok_or_else(|| self.error(token, &format!("{:?}", prefix)))
+    }
+
+    pub fn split_once(&self, token: &'a str, sep: &str) -> Result<(&'a str, &'a str), ParseError> {
+        token
+            .split_once(sep)
+            .ok_or_else(|| self.error(&token[token.len()..], &format!("{:?}", sep)))
+    }
+
+    // Character at a char index of the line, or an error at the end of the
+    // line if it is too short.
+    pub fn char_at(&self, index: usize, expected: &str) -> Result<char, ParseError> {
+        self.text
+            .chars()
+            .nth(index)
+            .ok_or_else(|| self.error_at_end(expected))
+    }
+
+    // Error pointing at the character at a char index of the line.
+    pub fn error_at_char(&self, index: usize, expected: &str) -> ParseError {
+        match self.text.char_indices().nth(index) {
+            Some((i, c)) => self.error(&self.text[i..i + c.len_utf8()], expected),
+            None => self.error_at_end(expected),
+        }
+    }
+}
+
+// Numbered lines of a puzzle input.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(i, text)| Line {
+        number: i + 1,
+        text: text,
+    })
+}
+
+// Error for an input that ends before something that was expected.
+pub fn end_of_input(input: &str, expected: &str) -> ParseError {
+    ParseError::new(input.lines().count() + 1, 1, expected, "end of input")
+}
@@ -0,0 +1,68 @@
+use std::ops::RangeInclusive;
+
+// A small pseudorandom number generator (SplitMix64) for making up puzzle
+// inputs. The same seed always gives the same numbers, so generated inputs can
+// be made again from their seed. It's not suitable for anything that needs
+// real randomness.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        return z ^ (z >> 31);
+    }
+
+    // Number from 0 up to but not including n, which must not be 0.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "should pick from at least one number");
+        // Scale rather than take the remainder, which is close enough to even
+        // for the small ranges used here.
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    // Number in an inclusive range, which must not be empty.
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(
+            start <= end,
+            "range {}..={} should not be empty",
+            start,
+            end
+        );
+        match (end.abs_diff(start)).checked_add(1) {
+            Some(len) => start.wrapping_add(self.below(len) as i64),
+            // The range is every i64.
+            None => self.next_u64() as i64,
+        }
+    }
+
+    // Index into something of a length, which must not be 0.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    // True with a probability from 0 to 1.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
@@ -0,0 +1,162 @@
+use std::fs::{self, OpenOptions};
+use std::path::{Path, PathBuf};
+
+use crate::data;
+use crate::{Part, Puzzle};
+
+// Where the day modules live, relative to the repo root.
+pub const DAYS_DIR: &str = "src/days";
+
+pub fn module_path(day: u8) -> PathBuf {
+    Path::new(DAYS_DIR).join(format!("day{}.rs", day))
+}
+
+// Source for a new day, which reads its input as lines and leaves both parts
+// to be written.
+pub fn module_source(day: u8) -> String {
+    format!(
+        "\
+use crate::parse::lines;
+use crate::{{Answer, Context, ParseError, Solution}};
+
+pub struct Day{day};
+
+impl Solution for Day{day} {{
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {{
+        return Ok(lines(input).map(|line| line.text.to_string()).collect());
+    }}
+
+    fn part_a(_: &Vec<String>, _: &Context) -> Answer {{
+        todo!()
+    }}
+
+    fn part_b(_: &Vec<String>, _: &Context) -> Answer {{
+        todo!()
+    }}
+}}
+",
+        day = day
+    )
+}
+
+// Day number of a "pub mod dayN;" line.
+fn mod_day(line: &str) -> Option<u8> {
+    line.strip_prefix("pub mod day")?
+        .strip_suffix(';')?
+        .parse()
+        .ok()
+}
+
+// Day number of a "day!(N, dayN::DayN)," line in the registry.
+fn entry_day(line: &str) -> Option<u8> {
+    line.trim_start()
+        .strip_prefix("day!(")?
+        .split_once(',')?
+        .0
+        .parse()
+        .ok()
+}
+
+// Adds a day to the source of days/mod.rs: its module declaration, and its
+// entry in DAYS, both kept in day order.
+pub fn register(source: &str, day: u8) -> Result<String, String> {
+    let mut lines: Vec<String> = source.lines().map(str::to_string).collect();
+
+    if lines.iter().any(|l| mod_day(l) == Some(day)) {
+        return Err(format!("day {} is already registered", day));
+    }
+    let mods: Vec<usize> = (0..lines.len())
+        .filter(|&i| mod_day(&lines[i]).is_some())
+        .collect();
+    let Some(&first_mod) = mods.first() else {
+        return Err("could not find the day modules".to_string());
+    };
+    let at = mods
+        .iter()
+        .rev()
+        .find(|&&i| mod_day(&lines[i]) < Some(day))
+        .map_or(first_mod, |&i| i + 1);
+    lines.insert(at, format!("pub mod day{};", day));
+
+    let Some(header) = lines
+        .iter()
+        .position(|l| l.starts_with("pub static DAYS: [Day; "))
+    else {
+        return Err("could not find the DAYS registry".to_string());
+    };
+    let count: usize = lines[header]
+        .strip_prefix("pub static DAYS: [Day; ")
+        .and_then(|rest| rest.split_once(']'))
+        .and_then(|(count, _)| count.parse().ok())
+        .ok_or("could not read the size of DAYS")?;
+    lines[header] = format!("pub static DAYS: [Day; {}] = [", count + 1);
+
+    let mut at = header + 1;
+    while at < lines.len() && entry_day(&lines[at]).is_some_and(|d| d < day) {
+        at += 1;
+    }
+    lines.insert(at, format!("    day!({}, day{}::Day{}),", day, day, day));
+
+    return Ok(lines.join("\n") + "\n");
+}
+
+// Creates empty sample input and output files for both parts of a day. With
+// more than one sample per part they are numbered from 1, like day 6's.
+// Existing files are never overwritten.
+pub fn create_samples(day: u8, count: u32) -> Result<Vec<PathBuf>, String> {
+    let variants: Vec<Option<u32>> = match count {
+        1 => vec![None],
+        n => (1..=n).map(Some).collect(),
+    };
+
+    let mut paths = Vec::new();
+    for part in [Part::A, Part::B] {
+        let puzzle = Puzzle {
+            day: day,
+            part: part,
+        };
+        for &variant in &variants {
+            let sample = data::sample(puzzle, variant);
+            paths.push(sample.input);
+            paths.push(sample.output);
+        }
+    }
+
+    if let Some(path) = paths.iter().find(|p| p.exists()) {
+        return Err(format!("{} already exists", path.display()));
+    }
+    for path in &paths {
+        OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path)
+            .map_err(|e| format!("could not create {}: {}", path.display(), e))?;
+    }
+    return Ok(paths);
+}
+
+// Adds a new day: writes its module, registers it in days/mod.rs, and creates
+// its sample files. Returns the files created or changed.
+pub fn new_day(day: u8, samples: u32) -> Result<Vec<PathBuf>, String> {
+    let module = module_path(day);
+    if module.exists() {
+        return Err(format!("{} already exists", module.display()));
+    }
+
+    let registry = Path::new(DAYS_DIR).join("mod.rs");
+    let source = fs::read_to_string(&registry)
+        .map_err(|e| format!("could not read {}: {}", registry.display(), e))?;
+    let source = register(&source, day)?;
+
+    let mut changed = create_samples(day, samples)?;
+    fs::write(&module, module_source(day))
+        .map_err(|e| format!("could not write {}: {}", module.display(), e))?;
+    fs::write(&registry, source)
+        .map_err(|e| format!("could not write {}: {}", registry.display(), e))?;
+
+    changed.push(module);
+    changed.push(registry);
+    return Ok(changed);
+}
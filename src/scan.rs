@@ -0,0 +1,288 @@
+use std::str::FromStr;
+
+use crate::parse::Line;
+use crate::ParseError;
+
+// Every integer in a token, each with its minus sign if it has one, in order.
+// Whatever is between them is skipped, so this reads e.g. "x=-2, y=15" or
+// "Each ore robot costs 4 ore" without caring about the words around them.
+pub fn int_tokens(token: &str) -> Vec<&str> {
+    let bytes = token.as_bytes();
+    let mut tokens = Vec::new();
+
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+
+        let start = if i > 0 && bytes[i - 1] == b'-' {
+            i - 1
+        } else {
+            i
+        };
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        tokens.push(&token[start..i]);
+    }
+
+    return tokens;
+}
+
+// Parses every integer in a token, as found by int_tokens.
+pub fn ints<'a, T: FromStr>(
+    line: Line<'a>,
+    token: &'a str,
+    expected: &str,
+) -> Result<Vec<T>, ParseError> {
+    int_tokens(token)
+        .into_iter()
+        .map(|t| line.parse(t, expected))
+        .collect()
+}
+
+// Value of a "label: value" token, such as "Starting items: 79, 98". The
+// label must match, but the whitespace around and within it doesn't matter.
+pub fn field<'a>(line: Line<'a>, token: &'a str, label: &str) -> Result<&'a str, ParseError> {
+    let (name, value) = line.split_once(token, ":")?;
+    if !name.split_whitespace().eq(label.split_whitespace()) {
+        return Err(line.error(name.trim(), &format!("{:?}", label)));
+    }
+    return Ok(value.trim());
+}
+
+// Part of the literal text of a template.
+enum Piece<'t> {
+    // A word, or a single punctuation character.
+    Text(&'t str),
+    // A run of whitespace, which matches any run of whitespace.
+    Space,
+}
+
+fn pieces(literal: &str) -> Vec<Piece<'_>> {
+    let mut pieces = Vec::new();
+    let mut rest = literal;
+
+    while !rest.is_empty() {
+        let trimmed = rest.trim_start();
+        if trimmed.len() < rest.len() {
+            pieces.push(Piece::Space);
+            rest = trimmed;
+            continue;
+        }
+
+        let end = match rest.chars().next() {
+            Some(c) if !c.is_alphanumeric() => c.len_utf8(),
+            _ => rest
+                .find(|c: char| c.is_whitespace() || !c.is_alphanumeric())
+                .unwrap_or(rest.len()),
+        };
+        pieces.push(Piece::Text(&rest[..end]));
+        rest = &rest[end..];
+    }
+
+    return pieces;
+}
+
+// Length of the start of s that matches the pieces, if it does.
+fn match_pieces(s: &str, pieces: &[Piece]) -> Option<usize> {
+    let mut len = 0;
+    for piece in pieces {
+        let rest = &s[len..];
+        match piece {
+            Piece::Text(text) => {
+                // Punctuation can have whitespace before it.
+                if !text.starts_with(char::is_alphanumeric) {
+                    len += rest.len() - rest.trim_start().len();
+                }
+                let rest = &s[len..];
+                if !rest.starts_with(text) {
+                    return None;
+                }
+                len += text.len();
+            }
+            Piece::Space => {
+                let skipped = rest.len() - rest.trim_start().len();
+                if skipped == 0 {
+                    return None;
+                }
+                len += skipped;
+            }
+        }
+    }
+    return Some(len);
+}
+
+// Matches a token against a template like "move {} from {} to {}" and returns
+// the trimmed value in place of each {}. Whitespace matches any whitespace, and
+// each value but the last is the shortest that lets the rest of it match.
+pub fn template<'a, const N: usize>(
+    line: Line<'a>,
+    token: &'a str,
+    template: &str,
+) -> Result<[&'a str; N], ParseError> {
+    let literals: Vec<&str> = template.split("{}").collect();
+    assert_eq!(
+        literals.len(),
+        N + 1,
+        "template {:?} should have {} placeholders",
+        template,
+        N
+    );
+
+    let token = token.trim();
+    let expected = |literal: &str| match literal.trim() {
+        "" => "a space".to_string(),
+        text => format!("{:?}", text),
+    };
+
+    let first = literals[0].trim_start();
+    let Some(mut pos) = match_pieces(token, &pieces(first)) else {
+        return Err(line.error(token, &expected(first)));
+    };
+
+    let mut values = Vec::with_capacity(N);
+    for (i, literal) in literals[1..].iter().enumerate() {
+        let rest = &token[pos..];
+        let literal = if i + 1 == N {
+            literal.trim_end()
+        } else {
+            literal
+        };
+
+        if literal.is_empty() && i + 1 == N {
+            values.push(rest.trim());
+            pos = token.len();
+            continue;
+        }
+
+        let pieces = pieces(literal);
+        let found = rest
+            .char_indices()
+            .map(|(j, _)| j)
+            .chain([rest.len()])
+            .find_map(|j| Some((j, match_pieces(&rest[j..], &pieces)?)));
+        let Some((len, matched)) = found else {
+            return Err(line.error(&token[token.len()..], &expected(literal)));
+        };
+
+        values.push(rest[..len].trim());
+        pos += len + matched;
+    }
+
+    if pos < token.len() {
+        return Err(line.error(&token[pos..], "the end of the line"));
+    }
+
+    return Ok(values
+        .try_into()
+        .unwrap_or_else(|_| unreachable!("one value per placeholder")));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(text: &str) -> Line<'_> {
+        Line {
+            number: 1,
+            text: text,
+        }
+    }
+
+    #[test]
+    fn int_tokens_keep_minus_signs() {
+        assert_eq!(int_tokens("x=-2, y=15"), ["-2", "15"]);
+        assert_eq!(int_tokens("Each ore robot costs 4 ore"), ["4"]);
+        assert_eq!(int_tokens("a-b 3-4"), ["3", "-4"]);
+        assert!(int_tokens("no numbers").is_empty());
+    }
+
+    #[test]
+    fn ints_point_at_the_bad_number() {
+        let l = line("x=1, y=99999999999");
+        assert_eq!(
+            ints::<i64>(l, l.text, "a coordinate"),
+            Ok(vec![1, 99999999999])
+        );
+        let e = ints::<i32>(l, l.text, "a coordinate").unwrap_err();
+        assert_eq!((e.column, e.found.as_str()), (8, "\"99999999999\""));
+    }
+
+    #[test]
+    fn field_ignores_whitespace_in_the_label() {
+        let l = line("  Starting   items:  79, 98 ");
+        assert_eq!(field(l, l.text, "Starting items"), Ok("79, 98"));
+        let e = field(l, l.text, "Operation").unwrap_err();
+        assert_eq!((e.column, e.expected.as_str()), (3, "\"Operation\""));
+        let l = line("Starting items 79");
+        assert!(field(l, l.text, "Starting items").is_err());
+    }
+
+    #[test]
+    fn template_values() {
+        let l = line("move 3 from 1 to 2");
+        assert_eq!(
+            template(l, l.text, "move {} from {} to {}"),
+            Ok(["3", "1", "2"])
+        );
+        let l = line("Monkey 0:");
+        assert_eq!(template(l, l.text, "Monkey {}:"), Ok(["0"]));
+        let l = line("2-4,6-8");
+        assert_eq!(template(l, l.text, "{}-{},{}-{}"), Ok(["2", "4", "6", "8"]));
+    }
+
+    #[test]
+    fn template_whitespace_is_loose() {
+        let l = line("  move  3   from 1 to 2 ");
+        assert_eq!(
+            template(l, l.text, "move {} from {} to {}"),
+            Ok(["3", "1", "2"])
+        );
+        // Punctuation can have whitespace before it, and values are trimmed.
+        let l = line("503 , 4");
+        assert_eq!(template(l, l.text, "{},{}"), Ok(["503", "4"]));
+        let l = line("root: pppw + sjmn");
+        assert_eq!(template(l, l.text, "{}: {}"), Ok(["root", "pppw + sjmn"]));
+        // But a space in the template needs one in the token.
+        let l = line("move3 from 1 to 2");
+        assert!(template::<3>(l, l.text, "move {} from {} to {}").is_err());
+    }
+
+    #[test]
+    fn template_values_are_shortest_but_the_last() {
+        let l = line("Valve AA has flow rate=0; tunnels lead to valves DD, II, BB");
+        assert_eq!(
+            template(
+                l,
+                l.text,
+                "Valve {} has flow rate={}; tunnel{} lead{} to valve{} {}"
+            ),
+            Ok(["AA", "0", "s", "", "s", "DD, II, BB"])
+        );
+        let l = line("a b c");
+        assert_eq!(template(l, l.text, "{} {}"), Ok(["a", "b c"]));
+    }
+
+    #[test]
+    fn template_errors() {
+        let l = line("mvoe 3 from 1 to 2");
+        let e = template::<3>(l, l.text, "move {} from {} to {}").unwrap_err();
+        assert_eq!((e.column, e.expected.as_str()), (1, "\"move\""));
+
+        let l = line("move 3 from 1");
+        let e = template::<3>(l, l.text, "move {} from {} to {}").unwrap_err();
+        assert_eq!((e.column, e.expected.as_str()), (14, "\"to\""));
+        assert_eq!(e.found, "end of line");
+
+        let l = line("R");
+        let e = template::<2>(l, l.text, "{} {}").unwrap_err();
+        assert_eq!((e.column, e.expected.as_str()), (2, "a space"));
+
+        let l = line("noop 3");
+        let e = template::<0>(l, l.text, "noop").unwrap_err();
+        assert_eq!((e.column, e.found.as_str()), (5, "\" 3\""));
+    }
+}
@@ -0,0 +1,211 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+use crate::stats::SearchStats;
+
+// Something to search through: nodes, and the steps between them.
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+
+    // Nodes one step away from a node, with the cost of each step. BFS treats
+    // every step as costing 1.
+    fn neighbours(&self, node: &Self::Node) -> Vec<(Self::Node, u64)>;
+
+    // Estimate of the cost from a node to the nearest goal, used by A*. It
+    // should never be more than the real cost, or A* may not find the
+    // cheapest path.
+    fn heuristic(&self, _node: &Self::Node) -> u64 {
+        0
+    }
+}
+
+// A path through a graph, from a start to a goal.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<N> {
+    // Nodes along the path, including both ends.
+    pub nodes: Vec<N>,
+    pub cost: u64,
+}
+
+impl<N> Path<N> {
+    // Number of steps along the path.
+    pub fn steps(&self) -> usize {
+        self.nodes.len() - 1
+    }
+}
+
+// What a search found: the goal, if it reached one, and the cheapest known
+// way to each node it reached on the way.
+pub struct Search<N> {
+    costs: HashMap<N, u64>,
+    // Node each node was reached from. Starts have no parent.
+    parents: HashMap<N, N>,
+    goal: Option<N>,
+    stats: SearchStats,
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+    fn new() -> Search<N> {
+        Search {
+            costs: HashMap::new(),
+            parents: HashMap::new(),
+            goal: None,
+            stats: SearchStats::default(),
+        }
+    }
+
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    // Cost of the cheapest path found to a node. This is only known to be the
+    // cheapest overall for the goal, or for every node if the search ran
+    // until there was nothing left to search.
+    pub fn cost(&self, node: &N) -> Option<u64> {
+        self.costs.get(node).copied()
+    }
+
+    // Costs of every node that was reached.
+    pub fn costs(&self) -> &HashMap<N, u64> {
+        &self.costs
+    }
+
+    // Path from a start to a node that was reached.
+    pub fn path_to(&self, node: &N) -> Option<Path<N>> {
+        let cost = self.cost(node)?;
+
+        let mut nodes = vec![node.clone()];
+        while let Some(parent) = self.parents.get(nodes.last().unwrap()) {
+            nodes.push(parent.clone());
+        }
+        nodes.reverse();
+
+        return Some(Path {
+            nodes: nodes,
+            cost: cost,
+        });
+    }
+
+    // How much work the search did.
+    pub fn stats(&self) -> &SearchStats {
+        &self.stats
+    }
+
+    // Path from a start to the goal, if one was reached.
+    pub fn path(&self) -> Option<Path<N>> {
+        self.path_to(self.goal.as_ref()?)
+    }
+
+    // Records a node as reached at a cost, unless it was already reached at
+    // least as cheaply. Returns whether it was recorded.
+    fn reach(&mut self, node: &N, parent: Option<&N>, cost: u64) -> bool {
+        if self.costs.get(node).is_some_and(|&c| c <= cost) {
+            return false;
+        }
+
+        self.costs.insert(node.clone(), cost);
+        match parent {
+            Some(parent) => self.parents.insert(node.clone(), parent.clone()),
+            None => self.parents.remove(node),
+        };
+        return true;
+    }
+}
+
+// Breadth-first search from any of the starts until a node matching the goal
+// is found, counting each step as 1. To search everything reachable, use a
+// goal that never matches.
+pub fn bfs<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    mut goal: impl FnMut(&G::Node) -> bool,
+) -> Search<G::Node> {
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if search.reach(&start, None, 0) {
+            queue.push_back(start);
+        }
+    }
+    search.stats.frontier(queue.len());
+
+    while let Some(node) = queue.pop_front() {
+        if goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+
+        search.stats.expand();
+        let cost = search.costs[&node] + 1;
+        for (next, _) in graph.neighbours(&node) {
+            if !search.costs.contains_key(&next) {
+                search.reach(&next, Some(&node), cost);
+                queue.push_back(next);
+            } else {
+                search.stats.prune("already reached", 1);
+            }
+        }
+        search.stats.frontier(queue.len());
+    }
+
+    return search;
+}
+
+// Cheapest-first search from any of the starts until a node matching the
+// goal is found, taking nodes in order of their cost plus the graph's estimate
+// of the cost left to the goal, which can avoid searching away from it. With
+// the default estimate of 0, this is Dijkstra's algorithm. To search
+// everything reachable, use a goal that never matches.
+pub fn astar<G: Graph>(
+    graph: &G,
+    starts: impl IntoIterator<Item = G::Node>,
+    mut goal: impl FnMut(&G::Node) -> bool,
+) -> Search<G::Node> {
+    let mut search = Search::new();
+    // Nodes aren't necessarily ordered, so the queue holds indices into this
+    // instead.
+    let mut queued = Vec::new();
+    let mut queue = BinaryHeap::new();
+
+    for start in starts {
+        if search.reach(&start, None, 0) {
+            queue.push(Reverse((graph.heuristic(&start), 0, queued.len())));
+            queued.push(start);
+        }
+    }
+    search.stats.frontier(queue.len());
+
+    while let Some(Reverse((_, cost, i))) = queue.pop() {
+        let node = queued[i].clone();
+        if search.costs[&node] < cost {
+            // Already searched from this node on a cheaper path.
+            search.stats.prune("reached more cheaply", 1);
+            continue;
+        }
+
+        if goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+
+        search.stats.expand();
+        for (next, step) in graph.neighbours(&node) {
+            let next_cost = cost + step;
+            if search.reach(&next, Some(&node), next_cost) {
+                queue.push(Reverse((
+                    next_cost + graph.heuristic(&next),
+                    next_cost,
+                    queued.len(),
+                )));
+                queued.push(next);
+            } else {
+                search.stats.prune("already reached", 1);
+            }
+        }
+        search.stats.frontier(queue.len());
+    }
+
+    return search;
+}
@@ -0,0 +1,342 @@
+use std::any::Any;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::frames::{Frame, Recorder};
+use crate::rng::Rng;
+use crate::stats::SearchStats;
+use crate::ParseError;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::A => write!(f, "a"),
+            Part::B => write!(f, "b"),
+        }
+    }
+}
+
+// A single puzzle, named like the data files: day number followed by part
+// letter, e.g. "14b".
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Puzzle {
+    pub day: u8,
+    pub part: Part,
+}
+
+impl fmt::Display for Puzzle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.day, self.part)
+    }
+}
+
+impl FromStr for Puzzle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Puzzle, String> {
+        let (day, part) = if let Some(day) = s.strip_suffix('a') {
+            (day, Part::A)
+        } else if let Some(day) = s.strip_suffix('b') {
+            (day, Part::B)
+        } else {
+            return Err(format!("puzzle '{}' should end in part a or b", s));
+        };
+        let day = day
+            .parse()
+            .map_err(|_| format!("puzzle '{}' should start with a day number", s))?;
+
+        return Ok(Puzzle {
+            day: day,
+            part: part,
+        });
+    }
+}
+
+// The answer to one part of a puzzle. Most answers are numbers, but some
+// (crate letters in day 5, the CRT picture in day 10, SNAFU in day 25) are
+// text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Answer {
+                    Answer::Number(n as i64)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Answer {
+        Answer::Text(s.to_string())
+    }
+}
+
+// A constant from the puzzle text that can be changed from the command line,
+// e.g. the number of rounds to run.
+#[derive(Clone, Copy, Debug)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: i64,
+    // Smallest and largest values the solution can cope with.
+    pub min: i64,
+    pub max: i64,
+    pub description: &'static str,
+}
+
+// Settings for one run of a solution.
+#[derive(Clone, Debug, Default)]
+pub struct Context {
+    params: HashMap<&'static str, i64>,
+    // Where simulations send their frames, if they're being captured.
+    recorder: Option<Arc<Mutex<Recorder>>>,
+    // Where searches report the work they did, if it's being counted.
+    stats: Option<Arc<Mutex<SearchStats>>>,
+}
+
+impl Context {
+    // Resolves the declared parameters, with the given (name, value) pairs
+    // overriding their defaults.
+    pub fn new(declared: &[Param], overrides: &[(String, i64)]) -> Result<Context, String> {
+        let mut params: HashMap<&'static str, i64> =
+            declared.iter().map(|p| (p.name, p.default)).collect();
+
+        for (name, value) in overrides {
+            let Some(param) = declared.iter().find(|p| p.name == name) else {
+                let names: Vec<&str> = declared.iter().map(|p| p.name).collect();
+                return Err(match names[..] {
+                    [] => format!("unknown parameter '{}', there are none", name),
+                    _ => format!(
+                        "unknown parameter '{}', expected one of: {}",
+                        name,
+                        names.join(", ")
+                    ),
+                });
+            };
+            if *value < param.min {
+                return Err(format!(
+                    "parameter '{}' should be at least {}",
+                    name, param.min
+                ));
+            }
+            if *value > param.max {
+                return Err(format!(
+                    "parameter '{}' should be at most {}",
+                    name, param.max
+                ));
+            }
+            params.insert(param.name, *value);
+        }
+
+        return Ok(Context {
+            params: params,
+            recorder: None,
+            stats: None,
+        });
+    }
+
+    // Captures the frames of the simulation into a recorder.
+    pub fn with_recorder(self, recorder: Arc<Mutex<Recorder>>) -> Context {
+        Context {
+            recorder: Some(recorder),
+            ..self
+        }
+    }
+
+    // Counts the work done by searches into the given stats.
+    pub fn with_stats(self, stats: Arc<Mutex<SearchStats>>) -> Context {
+        Context {
+            stats: Some(stats),
+            ..self
+        }
+    }
+
+    pub fn param(&self, name: &str) -> i64 {
+        *self
+            .params
+            .get(name)
+            .unwrap_or_else(|| panic!("parameter '{}' should be declared in PARAMS", name))
+    }
+
+    // Offers the next frame of a simulation, if frames are being captured.
+    // The frame is only drawn if the recorder keeps it.
+    pub fn frame(&self, draw: impl FnOnce() -> Frame) {
+        if let Some(recorder) = &self.recorder {
+            recorder
+                .lock()
+                .expect("recorder should not be poisoned")
+                .record(draw);
+        }
+    }
+
+    // Adds a search's counts into the stats, if they're being counted.
+    // Searches count into their own SearchStats as they go and report it
+    // once at the end, so that counting costs little when nobody's looking.
+    pub fn report_search(&self, search: &SearchStats) {
+        if let Some(stats) = &self.stats {
+            stats
+                .lock()
+                .expect("stats should not be poisoned")
+                .merge(search);
+        }
+    }
+}
+
+pub trait Solution {
+    // Parsed form of the puzzle input, shared by both parts.
+    type Input;
+
+    // Which parts this day has. Only day 25 lacks a part B.
+    const PARTS: &'static [Part] = &[Part::A, Part::B];
+
+    // Constants that can be overridden with --param.
+    const PARAMS: &'static [Param] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part_a(input: &Self::Input, ctx: &Context) -> Answer;
+    fn part_b(input: &Self::Input, ctx: &Context) -> Answer;
+
+    // Makes up a random input in the puzzle's format, for stress testing. The
+    // size scales it, e.g. as the number of lines or the side of a grid, and
+    // the input holds to whatever the solution assumes about real inputs.
+    // Days without a generator return None.
+    fn generate(_: &mut Rng, _: usize) -> Option<String> {
+        None
+    }
+
+    // Slow but plainly correct answer to a part, without the pruning or the
+    // assumptions about the input that the real solution makes, to check it
+    // against on small generated inputs. Days return None for the parts that
+    // have no reference solution.
+    fn reference(_: Part, _: &Self::Input, _: &Context) -> Option<Answer> {
+        None
+    }
+
+    // Problems with a parsed input that would trip up solving a part but
+    // that the parser can't see one line at a time, e.g. a monkey that isn't
+    // there to be asked. The raw input is given too, to point at where each
+    // problem is. All of them are reported, not just the first.
+    fn validate(_: Part, _: &str, _: &Self::Input) -> Vec<ParseError> {
+        Vec::new()
+    }
+}
+
+// How long the two halves of solving a puzzle took.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Timing {
+    pub parse: Duration,
+    pub solve: Duration,
+    // Whether the answer came from the cache rather than being solved, in
+    // which case both times are zero.
+    pub cached: bool,
+}
+
+impl Timing {
+    pub fn total(&self) -> Duration {
+        self.parse + self.solve
+    }
+}
+
+// Parses the raw puzzle input, failing on the first problem that would trip
+// up solving the given part.
+fn parse_valid<S: Solution>(part: Part, input: &str) -> Result<S::Input, ParseError> {
+    let parsed = S::parse(input)?;
+    match S::validate(part, input, &parsed).into_iter().next() {
+        Some(e) => Err(e),
+        None => Ok(parsed),
+    }
+}
+
+// Parses the raw puzzle input and solves the given part of it, timing each
+// step. Validating the input counts as parsing.
+pub fn solve_timed<S: Solution>(
+    part: Part,
+    input: &str,
+    ctx: &Context,
+) -> Result<(Answer, Timing), ParseError> {
+    let start = Instant::now();
+    let input = parse_valid::<S>(part, input)?;
+    let parsed = Instant::now();
+
+    let answer = match part {
+        Part::A => S::part_a(&input, ctx),
+        Part::B => S::part_b(&input, ctx),
+    };
+
+    let timing = Timing {
+        parse: parsed - start,
+        solve: parsed.elapsed(),
+        cached: false,
+    };
+    return Ok((answer, timing));
+}
+
+// Parses the raw puzzle input and solves the given part of it.
+pub fn solve<S: Solution>(part: Part, input: &str, ctx: &Context) -> Result<Answer, ParseError> {
+    solve_timed::<S>(part, input, ctx).map(|(answer, _)| answer)
+}
+
+// Parses the raw puzzle input and solves the given part of it with the
+// reference solution, if the part has one.
+pub fn solve_reference<S: Solution>(
+    part: Part,
+    input: &str,
+    ctx: &Context,
+) -> Result<Option<Answer>, ParseError> {
+    parse_valid::<S>(part, input).map(|input| S::reference(part, &input, ctx))
+}
+
+// Every problem with the raw puzzle input for the given part, without solving
+// it. Parsing stops at the first line it can't make sense of, so that is the
+// only problem reported if there is one.
+pub fn check<S: Solution>(part: Part, input: &str) -> Vec<ParseError> {
+    match S::parse(input) {
+        Ok(parsed) => S::validate(part, input, &parsed),
+        Err(e) => vec![e],
+    }
+}
+
+// Message a solution panicked with, from the payload that catching the panic
+// gives back.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        return s.to_string();
+    }
+    if let Some(s) = payload.downcast_ref::<String>() {
+        return s.clone();
+    }
+    return String::from("(no message)");
+}
@@ -0,0 +1,99 @@
+use std::cmp;
+use std::fmt;
+
+use crate::cycle::Cycle;
+
+// Counts of the work a search did, for seeing where its time goes and which
+// of its pruning rules pay off.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SearchStats {
+    // States taken off the frontier and searched from.
+    pub expanded: u64,
+    // States left out by each pruning rule, in the order the rules first
+    // pruned something. There are only ever a few rules, so a list is quicker
+    // to count into than a map.
+    pub pruned: Vec<(&'static str, u64)>,
+    // Most states waiting to be searched at once.
+    pub peak_frontier: usize,
+    // Times a state's answer was already known, and not worked out again.
+    pub memo_hits: u64,
+    // Where a simulation that was run until it repeated started repeating.
+    pub cycle: Option<Cycle>,
+}
+
+impl SearchStats {
+    pub fn expand(&mut self) {
+        self.expanded += 1;
+    }
+
+    // Counts states left out by a pruning rule, named for the stats report.
+    pub fn prune(&mut self, rule: &'static str, states: u64) {
+        if states == 0 {
+            return;
+        }
+        match self.pruned.iter_mut().find(|(r, _)| *r == rule) {
+            Some((_, count)) => *count += states,
+            None => self.pruned.push((rule, states)),
+        }
+    }
+
+    // Notes the size of the frontier, keeping the largest seen.
+    pub fn frontier(&mut self, len: usize) {
+        self.peak_frontier = cmp::max(self.peak_frontier, len);
+    }
+
+    pub fn memo_hit(&mut self) {
+        self.memo_hits += 1;
+    }
+
+    pub fn found_cycle(&mut self, cycle: Cycle) {
+        self.cycle = Some(cycle);
+    }
+
+    // Adds in the counts from another search, e.g. one of several a solution
+    // runs. Their frontiers are never around at once, so the peak is the
+    // larger of the two.
+    pub fn merge(&mut self, other: &SearchStats) {
+        self.expanded += other.expanded;
+        for &(rule, states) in &other.pruned {
+            self.prune(rule, states);
+        }
+        self.frontier(other.peak_frontier);
+        self.memo_hits += other.memo_hits;
+        self.cycle = other.cycle.or(self.cycle);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        *self == SearchStats::default()
+    }
+}
+
+// One count per line, with the pruning rules and then any cycle last. Counts
+// of 0 are left out, as most searches have nothing to count for some rows,
+// but a cycle can start at 0.
+impl fmt::Display for SearchStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut rows = vec![
+            ("states expanded".to_string(), self.expanded),
+            ("peak frontier".to_string(), self.peak_frontier as u64),
+            ("memo hits".to_string(), self.memo_hits),
+        ];
+        for &(rule, states) in &self.pruned {
+            rows.push((format!("pruned: {}", rule), states));
+        }
+        rows.retain(|&(_, count)| count > 0);
+        if let Some(cycle) = self.cycle {
+            rows.push(("cycle start".to_string(), cycle.start as u64));
+            rows.push(("cycle length".to_string(), cycle.len as u64));
+        }
+
+        let width = rows.iter().map(|(name, _)| name.len()).max().unwrap_or(0);
+        for (i, (name, count)) in rows.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{:<width$}  {}", name, count, width = width)?;
+        }
+        return Ok(());
+    }
+}
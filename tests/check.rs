@@ -0,0 +1,174 @@
+// Feeds inputs that parse but that a solution can't cope with to check, which
+// should turn each of them down with an error rather than leave the solution
+// to panic on it.
+
+use advent2022::days;
+use advent2022::{ParseError, Puzzle};
+
+fn check(puzzle: &str, input: &str) -> Vec<ParseError> {
+    let puzzle: Puzzle = puzzle.parse().expect("test puzzle should be valid");
+    let day = days::get(puzzle.day).expect("day should have a solution");
+    return day.check(puzzle.part, input);
+}
+
+// Asserts that check finds a problem expecting the given thing, at the given
+// line.
+fn assert_rejects(puzzle: &str, input: &str, line: usize, expected: &str) {
+    let problems = check(puzzle, input);
+    assert!(
+        problems
+            .iter()
+            .any(|p| p.line == line && p.expected.contains(expected)),
+        "{} should have expected {:?} at line {}, but found {:?}",
+        puzzle,
+        expected,
+        line,
+        problems
+    );
+}
+
+fn assert_accepts(puzzle: &str, input: &str) {
+    let problems = check(puzzle, input);
+    assert!(problems.is_empty(), "{}: {:?}", puzzle, problems);
+}
+
+#[test]
+fn day1_needs_three_elves() {
+    assert_accepts("1a", "1\n\n2\n");
+    assert_rejects("1b", "1\n\n2\n", 4, "at least 3 elves");
+    assert_accepts("1b", "1\n\n2\n\n3\n");
+}
+
+#[test]
+fn day3_needs_whole_groups() {
+    let two = "vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\n";
+    assert_accepts("3a", two);
+    assert_rejects("3b", two, 3, "finish the group of 3");
+    assert_accepts("3b", &format!("{}PmmdzqPrVvPwwTWBwg\n", two));
+}
+
+#[test]
+fn day6_needs_a_marker() {
+    assert_rejects("6a", "abcabcabc\n", 1, "a marker of 4 different characters");
+    assert_accepts("6a", "abcd\n");
+    assert_rejects("6b", "abcd\n", 1, "a marker of 14 different characters");
+}
+
+#[test]
+fn day7_needs_room_for_the_update() {
+    let small = "$ cd /\n$ ls\n100 a\n";
+    assert_accepts("7a", small);
+    assert_rejects("7b", small, 4, "files adding up to between");
+    assert_accepts("7b", "$ cd /\n$ ls\n50000000 a\n");
+}
+
+#[test]
+fn day8_needs_a_tree() {
+    assert_rejects("8a", "", 1, "a row of tree heights");
+    assert_rejects("8a", "\n", 1, "a tree height");
+    assert_rejects("8b", "\n\n", 1, "a tree height");
+    assert_accepts("8b", "5\n");
+}
+
+#[test]
+fn day11_targets_should_be_monkeys() {
+    let monkey = |n: usize, target: usize| {
+        format!(
+            "Monkey {}:\n  Starting items: 79\n  Operation: new = old * 19\n  \
+             Test: divisible by 23\n    If true: throw to monkey {}\n    \
+             If false: throw to monkey 0\n",
+            n, target
+        )
+    };
+    assert_accepts("11a", &format!("{}\n{}", monkey(0, 1), monkey(1, 0)));
+    assert_rejects(
+        "11a",
+        &format!("{}\n{}", monkey(0, 5), monkey(1, 0)),
+        5,
+        "a monkey number below 2",
+    );
+    assert_rejects("11a", &monkey(0, 0), 7, "another monkey");
+}
+
+#[test]
+fn day12_end_should_be_reachable() {
+    assert_rejects("12a", "SzE\n", 1, "an end E that can be climbed to from S");
+    assert_rejects("12b", "SzE\n", 1, "an end E that can be climbed to");
+    assert_accepts("12a", "SbcdefghijklmnopqrstuvwxyE\n");
+}
+
+#[test]
+fn day13_needs_pairs() {
+    let three = "[1]\n[2]\n\n[3]\n";
+    assert_rejects("13a", three, 5, "a packet to pair with the last one");
+    assert_accepts("13a", "[1]\n[2]\n\n[3]\n[4]\n");
+}
+
+#[test]
+fn day16_tunnels_should_lead_to_valves() {
+    let valves = |to: &str| {
+        format!(
+            "Valve AA has flow rate=0; tunnels lead to valves BB, {}\n\
+             Valve BB has flow rate=13; tunnel leads to valve AA\n",
+            to
+        )
+    };
+    assert_accepts("16a", &valves("AA"));
+    assert_rejects("16a", &valves("ZZ"), 1, "the name of a valve");
+    assert_rejects(
+        "16a",
+        "Valve BB has flow rate=13; tunnel leads to valve BB\n",
+        2,
+        "a valve named AA",
+    );
+}
+
+#[test]
+fn day21_operations_should_work_out() {
+    assert_accepts("21a", "root: a / b\na: 6\nb: 3\n");
+    assert_rejects("21a", "root: a / b\na: 7\nb: 0\n", 1, "a whole result");
+    assert_rejects("21a", "root: a - b\na: 1\nb: 3\n", 1, "a whole result");
+    assert_rejects("21a", "a: 7\n", 2, "a monkey named root");
+}
+
+#[test]
+fn day21_humn_should_be_worked_out() {
+    assert_accepts("21b", "root: a + b\na: humn * c\nc: 2\nhumn: 1\nb: 6\n");
+
+    let whole = "an operation with a whole result in range";
+    let undone = "an operation humn's number can be worked back through";
+    for (input, line, expected) in [
+        ("root: humn + b\nhumn: 1\nb: c / d\nc: 1\nd: 0\n", 3, whole),
+        ("root: humn + b\nhumn: 1\nb: c - d\nc: 1\nd: 5\n", 3, whole),
+        ("root: a + b\na: humn * c\nc: 0\nhumn: 1\nb: 5\n", 2, undone),
+        ("root: a + b\na: c / humn\nc: 3\nhumn: 1\nb: 0\n", 2, undone),
+        ("root: a + b\na: humn * c\nc: 2\nhumn: 1\nb: 5\n", 2, undone),
+    ] {
+        assert_rejects("21b", input, line, expected);
+    }
+}
+
+#[test]
+fn day22_map_should_fold_into_a_cube() {
+    assert_accepts("22a", "...\n\n1\n");
+    assert_rejects("22b", "...\n\n1\n", 2, "a map of 6 square faces");
+    let cube = "a map that folds up into a cube";
+    assert_rejects("22b", "......\n\n1\n", 2, cube);
+    assert_rejects("22b", "..\n..\n.\n.\n\n1\n", 5, cube);
+    assert_accepts("22b", "  .\n...\n  ..\n\n1\n");
+    assert_accepts("22b", " .\n...\n .\n .\n\n1\n");
+}
+
+#[test]
+fn day23_needs_an_elf() {
+    assert_rejects("23a", "...\n...\n", 3, "an elf");
+    assert_accepts("23a", "...\n.#.\n");
+}
+
+#[test]
+fn day24_valley_should_be_crossable() {
+    let blocked = "#.##\n#<>#\n##.#\n";
+    assert_rejects("24a", blocked, 3, "reached through the blizzards");
+    assert_rejects("24b", blocked, 3, "reached through the blizzards");
+    assert_accepts("24b", "#.##\n#..#\n##.#\n");
+}
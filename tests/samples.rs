@@ -0,0 +1,93 @@
+// Runs every sample input in data/ through its solution in-process and checks
+// the answer against the matching sample output. Each sample file is its own
+// test case, which #[test] functions can't express for a directory that
+// changes, so this uses its own harness.
+
+use std::env;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::process::ExitCode;
+
+use advent2022::config::{self, InputKind};
+use advent2022::data::{self, Sample};
+use advent2022::days;
+
+// Checks one sample, returning a description of the failure if it fails.
+fn check(sample: &Sample) -> Result<(), String> {
+    let day = days::get(sample.puzzle.day)
+        .filter(|d| d.parts.contains(&sample.puzzle.part))
+        .ok_or(format!("no solution for puzzle {}", sample.puzzle))?;
+
+    let mut input = fs::File::open(&sample.input)
+        .map_err(|e| format!("could not read {}: {}", sample.input.display(), e))?;
+    let expected = fs::read_to_string(&sample.output)
+        .map_err(|e| format!("could not read {}: {}", sample.output.display(), e))?;
+
+    let ctx = day.context(&day.configured_params(InputKind::Sample, &[]))?;
+    let answer = panic::catch_unwind(AssertUnwindSafe(|| {
+        day.solve_from(sample.puzzle.part, &mut input, &ctx)
+    }))
+    .map_err(|_| "solution panicked".to_string())?
+    .map_err(|e| e.to_string())?
+    .0
+    .to_string();
+
+    if answer != expected.trim_end() {
+        return Err(format!(
+            "expected:\n{}\ngot:\n{}",
+            expected.trim_end(),
+            answer
+        ));
+    }
+    return Ok(());
+}
+
+fn main() -> ExitCode {
+    // Accept a name filter like libtest does, ignoring any flags.
+    let filter = env::args().skip(1).find(|a| !a.starts_with('-'));
+
+    if let Err(e) = config::load() {
+        eprintln!("{}", e);
+        return ExitCode::FAILURE;
+    }
+    let mut samples = data::all_samples().expect("data directory should be readable");
+    let total = samples.len();
+    if let Some(filter) = &filter {
+        samples.retain(|s| s.file_stem().contains(filter.as_str()));
+    }
+
+    println!("\nrunning {} tests", samples.len());
+
+    let mut failures = Vec::new();
+    for sample in &samples {
+        let name = sample.file_stem();
+        match check(sample) {
+            Ok(()) => println!("test {} ... ok", name),
+            Err(e) => {
+                println!("test {} ... FAILED", name);
+                failures.push((name, e));
+            }
+        }
+    }
+
+    if !failures.is_empty() {
+        println!("\nfailures:");
+        for (name, e) in &failures {
+            println!("\n---- {} ----\n{}", name, e);
+        }
+    }
+
+    println!(
+        "\ntest result: {}. {} passed; {} failed; 0 ignored; 0 measured; {} filtered out\n",
+        if failures.is_empty() { "ok" } else { "FAILED" },
+        samples.len() - failures.len(),
+        failures.len(),
+        total - samples.len()
+    );
+
+    if failures.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}